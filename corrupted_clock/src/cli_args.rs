@@ -3,23 +3,58 @@ use std::path::{Path, PathBuf};
 use crate::constants;
 use clap::{Parser, Subcommand};
 
+mod adjust_args;
+mod cap_args;
+mod carry_over_arg;
 mod clock_kind;
 mod clock_reference;
+mod clock_state_arg;
 mod column_show_arg;
 mod create_command;
+mod duration_style_arg;
+mod edit_args;
+mod exclusive_args;
 mod existing_clock_reference;
 mod get_clock_args;
+mod goal_args;
 mod list_args;
+mod local_date;
 mod many_clock_reference_kind;
+mod pomodoro_args;
+mod precision_arg;
+mod refill_period_arg;
+mod reset_args;
+mod settings_args;
+mod snooze_args;
+mod speed_args;
+mod stage_arg;
+mod time_jump_policy_arg;
 
+pub use adjust_args::AdjustArgs;
+pub use cap_args::CapArgs;
+pub use carry_over_arg::CarryOverArg;
 pub use clock_kind::{ClockKind, ClockKindArg};
 pub use clock_reference::ClockReference;
+pub use clock_state_arg::{ClockStateArg, SortBy};
 pub use column_show_arg::ColumnShowArg;
 pub use create_command::CreateCommand;
+pub use duration_style_arg::DurationStyleArg;
+pub use edit_args::{EditArgs, EditSegmentCommand};
+pub use exclusive_args::{ExclusiveArgs, ExclusiveModeArg};
 pub use existing_clock_reference::{ExistingClockKindReference, ExistingClockReference};
 pub use get_clock_args::GetClockArgs;
+pub use goal_args::GoalArgs;
 pub use list_args::ListArgs;
 pub use many_clock_reference_kind::ManyClockReferenceKind;
+pub use pomodoro_args::PomodoroArgs;
+pub use precision_arg::PrecisionArg;
+pub use refill_period_arg::RefillPeriodArg;
+pub use reset_args::ResetArgs;
+pub use settings_args::{SettingChange, SettingsArgs};
+pub use snooze_args::{CountDownTimeChange, SnoozeArgs};
+pub use speed_args::SpeedArgs;
+pub use stage_arg::InvalidStage;
+pub use time_jump_policy_arg::TimeJumpPolicyArg;
 
 #[derive(Debug, Parser)]
 #[command(version, about, long_about = None)]
@@ -52,7 +87,7 @@ impl AppCliArgs {
 pub enum AppSubCommands {
     /// alias: c
     #[command(alias = "c")]
-    Create(CreateCommand),
+    Create(Box<CreateCommand>),
     /// alias: g
     #[command(alias = "g")]
     Get(GetClockArgs),
@@ -70,5 +105,49 @@ pub enum AppSubCommands {
     Pause(ExistingClockReference),
     /// alias: rs
     #[command(alias = "rs")]
-    Reset(ExistingClockReference),
+    Reset(ResetArgs),
+    /// Records a lap without pausing the clock
+    ///
+    /// alias: lp
+    #[command(alias = "lp")]
+    Lap(ExistingClockReference),
+    /// Retroactively changes the running segments of a clock
+    ///
+    /// Segments are numbered starting with 1 as shown by the subcommand "get".
+    /// A count down or sequence only shows and edits the segments up to zero.
+    /// Dates have the syntax: <year>-<month>-<day> <hours>:<minutes>[:<seconds>] [<zone>]
+    /// A date without zone is read in the time zone of the clock or else in the local one.
+    ///
+    /// alias: e
+    #[command(alias = "e")]
+    Edit(EditArgs),
+    /// Adds or subtracts time from the passed time of a clock
+    ///
+    /// alias: a
+    #[command(alias = "a")]
+    Adjust(AdjustArgs),
+    /// alias: gl
+    #[command(alias = "gl")]
+    Goal(GoalArgs),
+    /// alias: sn
+    #[command(alias = "sn")]
+    Snooze(SnoozeArgs),
+    /// Resumes the given clock and pauses every other running clock in its scope.
+    /// Without an exclusive mode, all other running clocks are paused.
+    ///
+    /// alias: s
+    #[command(alias = "s")]
+    Switch(ClockReference),
+    /// alias: ex
+    #[command(alias = "ex")]
+    Exclusive(ExclusiveArgs),
+    /// alias: cp
+    #[command(alias = "cp")]
+    Cap(CapArgs),
+    /// alias: st
+    #[command(alias = "st")]
+    Settings(SettingsArgs),
+    /// alias: sp
+    #[command(alias = "sp")]
+    Speed(SpeedArgs),
 }
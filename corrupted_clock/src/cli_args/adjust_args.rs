@@ -0,0 +1,38 @@
+use clap::Args;
+use corrupted_clock_util::timing::ClockDuration;
+
+use super::ClockReference;
+
+#[derive(Debug, Args)]
+pub struct AdjustArgs {
+    #[command(flatten)]
+    reference: ClockReference,
+    #[arg(allow_hyphen_values = true, value_parser = ClockDuration::parse_signed)]
+    /// Syntax: [+|-]<duration>
+    ///
+    /// A leading "-" subtracts the duration. See the subcommand "create" for the syntax of a duration.
+    ///
+    /// # Examples
+    ///
+    /// Adds 15 minutes: +15:00
+    ///
+    /// Subtracts 5 minutes: -5:00
+    amount: ClockDuration,
+    #[arg(short, long)]
+    /// Why the time was adjusted
+    reason: Option<String>,
+}
+
+impl AdjustArgs {
+    pub fn reference(&self) -> &ClockReference {
+        &self.reference
+    }
+
+    pub fn amount(&self) -> ClockDuration {
+        self.amount
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+}
@@ -0,0 +1,27 @@
+use clap::Args;
+use corrupted_clock_util::timing::ClockDuration;
+
+#[derive(Debug, Args)]
+/// Sets or removes the cap of a stopwatch.
+/// A stopwatch stops on its own once its passed time reaches the cap.
+pub struct CapArgs {
+    /// Name of the stopwatch
+    name: String,
+    #[arg(required_unless_present = "remove")]
+    /// Maximum passed time. See the subcommand "create" for the syntax of a duration.
+    cap: Option<ClockDuration>,
+    #[arg(short, long, conflicts_with = "cap")]
+    /// Removes the cap
+    remove: bool,
+}
+
+impl CapArgs {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// None if the cap is to be removed
+    pub fn cap(&self) -> Option<ClockDuration> {
+        self.cap
+    }
+}
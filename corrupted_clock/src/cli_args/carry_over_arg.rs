@@ -0,0 +1,22 @@
+use clap::ValueEnum;
+use corrupted_clock_util::timing::CarryOver;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CarryOverArg {
+    /// Every period starts with the allowance alone
+    None,
+    /// Unused allowance is added to the next period
+    Unused,
+    /// Unused allowance is added to the next period and overdrawn allowance is taken from it
+    All,
+}
+
+impl From<CarryOverArg> for CarryOver {
+    fn from(value: CarryOverArg) -> Self {
+        match value {
+            CarryOverArg::None => CarryOver::None,
+            CarryOverArg::Unused => CarryOver::Unused,
+            CarryOverArg::All => CarryOver::All,
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use clap::{Args, ValueEnum};
+use corrupted_clock_util::timing::ClockType;
 
 use crate::constants;
 
@@ -11,6 +12,15 @@ pub enum ClockKind {
     /// alias: cd
     #[value(alias(constants::COUNT_DOWN_ALIASE))]
     CountDown,
+    /// alias: pd
+    #[value(alias(constants::POMODORO_ALIASE))]
+    Pomodoro,
+    /// alias: sq
+    #[value(alias(constants::SEQUENCE_ALIASE))]
+    Sequence,
+    /// alias: bg
+    #[value(alias(constants::BUDGET_ALIASE))]
+    Budget,
 }
 
 impl std::fmt::Display for ClockKind {
@@ -18,6 +28,21 @@ impl std::fmt::Display for ClockKind {
         match self {
             ClockKind::StopWatch => f.write_str("stop watch"),
             ClockKind::CountDown => f.write_str("count down"),
+            ClockKind::Pomodoro => f.write_str("pomodoro"),
+            ClockKind::Sequence => f.write_str("sequence"),
+            ClockKind::Budget => f.write_str("budget"),
+        }
+    }
+}
+
+impl From<ClockKind> for ClockType {
+    fn from(value: ClockKind) -> Self {
+        match value {
+            ClockKind::StopWatch => ClockType::Stopwatch,
+            ClockKind::CountDown => ClockType::CountDown,
+            ClockKind::Pomodoro => ClockType::Pomodoro,
+            ClockKind::Sequence => ClockType::Sequence,
+            ClockKind::Budget => ClockType::Budget,
         }
     }
 }
@@ -0,0 +1,30 @@
+use clap::ValueEnum;
+use corrupted_clock_util::timing::ClockState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ClockStateArg {
+    Running,
+    Paused,
+    Scheduled,
+    Finished,
+}
+
+impl From<ClockStateArg> for ClockState {
+    fn from(value: ClockStateArg) -> Self {
+        match value {
+            ClockStateArg::Running => ClockState::Running,
+            ClockStateArg::Paused => ClockState::Paused,
+            ClockStateArg::Scheduled => ClockState::Scheduled,
+            ClockStateArg::Finished => ClockState::Finished,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+pub enum SortBy {
+    #[default]
+    Name,
+    /// Running clocks first, then paused, scheduled and finished ones.
+    /// Clocks with the same state are sorted by name.
+    State,
+}
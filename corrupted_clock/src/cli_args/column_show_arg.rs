@@ -1,6 +1,9 @@
 use clap::Args;
+use corrupted_clock_util::timing::{DurationFormat, Tz};
 
-use crate::AtLeastOne;
+use crate::{constants, display_zone::DisplayZone, listing_items_param::TableFormat, AtLeastOne};
+
+use super::{DurationStyleArg, PrecisionArg};
 
 #[derive(Debug, Args)]
 pub struct ColumnShowArg {
@@ -9,10 +12,40 @@ pub struct ColumnShowArg {
     ///
     /// Note: Must be greater than zero
     colums_num: Option<AtLeastOne>,
+    #[arg(
+        long,
+        default_value = "days",
+        env = constants::env_var_prefix!("DURATION_STYLE")
+    )]
+    /// How durations are written within the tables
+    duration_style: DurationStyleArg,
+    #[arg(
+        long,
+        default_value = "seconds",
+        env = constants::env_var_prefix!("PRECISION")
+    )]
+    /// Smallest unit shown for durations within the tables. Values are rounded to it.
+    precision: PrecisionArg,
+    #[arg(long, env = constants::env_var_prefix!("TZ"))]
+    /// IANA time zone in which dates are shown, like "Europe/Berlin".
+    ///
+    /// Without it, dates of a single clock are shown in the time zone of the clock
+    /// and all other dates in the local time zone.
+    tz: Option<Tz>,
+    #[arg(long, conflicts_with = "tz")]
+    /// Shows dates in UTC
+    utc: bool,
 }
 
 impl ColumnShowArg {
     pub fn colums_num(&self) -> Option<AtLeastOne> {
         self.colums_num
     }
+
+    pub fn table_format(&self) -> TableFormat {
+        TableFormat::new(
+            DurationFormat::new(self.duration_style.into(), self.precision.into()),
+            DisplayZone::new(self.utc, self.tz),
+        )
+    }
 }
@@ -1,13 +1,16 @@
-use chrono::{Local, TimeZone, Utc};
 use clap::Args;
 use corrupted_clock_util::{
     parsed_date::{InvalidDateFormat, ParsedDate},
     timing::{
-        validate_if_date_is_not_in_future, ClockDuration, InvalidDateInFuture, UtcDateTime,
-        UtcTimeImpl,
+        CarryOver, ClockDuration, PomodoroConfig, RefillPeriod, RepeatPolicy, Speed, Stage, Tz,
+        UtcDateTime,
     },
 };
 
+use crate::{constants, AtLeastOne, ParseNonPosZeroError};
+
+use super::{local_date, stage_arg, CarryOverArg, PomodoroArgs, RefillPeriodArg};
+
 #[derive(Debug, Args)]
 pub struct CreateCommand {
     name: Option<String>,
@@ -42,25 +45,100 @@ pub struct CreateCommand {
     ///
     /// 12
     to_count_down: Option<ClockDuration>,
-    #[arg(short, long ,value_parser = parse_start_date)]
-    /// Valid syntax: <year>-<month>-<day> <hours>:<minutes>:<seconds>
-    /// year: positive number
-    /// months: [0-1]?<1-9>
-    /// year: positive number
-    /// year: positive number
-    /// year: positive number
-    /// year: positive number
-    start_date: Option<UtcDateTime>,
-}
-
-use thiserror::Error;
-
-#[derive(Debug, Clone, Copy, Error)]
-pub enum InvalidStartDate {
-    #[error("{0}")]
-    InFuture(#[from] InvalidDateInFuture),
-    #[error("{0}")]
-    Invalid(#[from] InvalidDateFormat),
+    #[arg(short, long)]
+    /// A start date in the future schedules the clock. It starts running automatically at this date.
+    ///
+    /// Valid syntax: <year>-<month>-<day> <hours>:<minutes>[:<seconds>] [<zone>]
+    ///
+    /// Instead of a date, "today" or "tomorrow" can be given. Example: tomorrow 09:00
+    ///
+    /// A date without zone is read in the zone of option "time-zone" or else in the local one.
+    /// The optional zone is an IANA time zone or a UTC offset.
+    /// Examples: 2024-05-01 08:00 Europe/Berlin, 2024-05-01 08:00+02:00, 2024-05-01 08:00Z
+    start_date: Option<ParsedDate>,
+    #[arg(long)]
+    /// Count down keeps counting past zero and shows how much it is overdue.
+    /// Has no effect on a stopwatch.
+    overrun: bool,
+    #[arg(short, long, value_parser = parse_repeat_policy)]
+    /// Count down starts over after reaching zero. Has no effect on a stopwatch.
+    ///
+    /// Valid values: "forever" or a number greater than zero for the number of cycles in total
+    repeat: Option<RepeatPolicy>,
+    #[arg(
+        short,
+        long,
+        conflicts_with_all = ["to_count_down", "alarm", "pomodoro", "sequence", "budget"]
+    )]
+    /// Planned time budget of a stopwatch. The stopwatch keeps running past it.
+    /// See option "to-count-down" for the syntax of a duration.
+    goal: Option<ClockDuration>,
+    #[arg(long, conflicts_with_all = ["to_count_down", "alarm", "pomodoro", "sequence", "budget"])]
+    /// Maximum passed time of a stopwatch. It stops on its own once its passed time reaches the cap.
+    /// Falls back to the default cap of the settings.
+    /// See option "to-count-down" for the syntax of a duration.
+    cap: Option<ClockDuration>,
+    #[arg(
+        short,
+        long,
+        conflicts_with_all = ["to_count_down", "start_date", "repeat", "pomodoro", "sequence", "budget"]
+    )]
+    /// Creates a count down which reaches zero at the given date and time.
+    /// Pausing has no effect on it unless "follow-pauses" is given.
+    ///
+    /// Valid syntax: <year>-<month>-<day> <hours>:<minutes>[:<seconds>] [<zone>]
+    ///
+    /// Instead of a date, "today" or "tomorrow" can be given. Example: tomorrow 09:00
+    ///
+    /// A date without zone is read in the zone of option "time-zone" or else in the local one.
+    /// The optional zone is an IANA time zone or a UTC offset.
+    /// Examples: 2024-05-01 08:00 Europe/Berlin, 2024-05-01 08:00+02:00, 2024-05-01 08:00Z
+    alarm: Option<ParsedDate>,
+    #[arg(long, requires = "alarm")]
+    /// Pausing the alarm postpones the moment at which it reaches zero
+    follow_pauses: bool,
+    #[arg(short, long, conflicts_with_all = ["to_count_down", "sequence", "budget"])]
+    /// Creates a pomodoro which moves through work and break phases
+    pomodoro: bool,
+    #[command(flatten)]
+    pomodoro_config: PomodoroArgs,
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_parser = stage_arg::parse_stage,
+        conflicts_with_all = ["to_count_down", "repeat", "overrun", "budget"]
+    )]
+    /// Creates a sequence whose stages are played one after another.
+    /// See option "to-count-down" for the syntax of a duration.
+    ///
+    /// Valid syntax: <label>=<duration>[,<label>=<duration>]...
+    ///
+    /// Example: prep=2:00,run=20:00,cooldown=5:00
+    sequence: Vec<Stage>,
+    #[arg(long, conflicts_with_all = ["to_count_down", "repeat", "overrun"])]
+    /// Creates a budget with this allowance. It is used up while the budget runs
+    /// and refilled at the start of every period.
+    /// See option "to-count-down" for the syntax of a duration.
+    budget: Option<ClockDuration>,
+    #[arg(long, requires = "budget")]
+    /// Calendar period after which the allowance of a budget is refilled. Defaults to weekly.
+    /// Periods start at midnight in the zone of option "time-zone" or else in UTC.
+    refill: Option<RefillPeriodArg>,
+    #[arg(long, requires = "budget")]
+    /// What is left of a period is passed on to the next one. Defaults to none.
+    carry_over: Option<CarryOverArg>,
+    #[arg(long)]
+    /// Group of the clock. Clocks of the same group exclude each other
+    /// if the exclusive mode is "group".
+    group: Option<String>,
+    #[arg(long)]
+    /// IANA time zone in which the clock is used, like "Europe/Berlin".
+    /// Dates of the clock are shown in it unless another time zone is chosen for the output.
+    time_zone: Option<Tz>,
+    #[arg(long)]
+    /// Factor by which the passed time grows compared to the real time, like 10 or 0.5 or 1/3.
+    /// See the subcommand "speed" to change it later.
+    speed: Option<Speed>,
 }
 
 impl CreateCommand {
@@ -72,16 +150,79 @@ impl CreateCommand {
         self.to_count_down
     }
 
-    pub fn start_date(&self) -> Option<UtcDateTime> {
+    pub fn start_date(&self) -> Result<Option<UtcDateTime>, InvalidDateFormat> {
         self.start_date
+            .map(|date| local_date::to_utc_in_clock_zone(date, self.time_zone))
+            .transpose()
+    }
+
+    pub fn overrun(&self) -> bool {
+        self.overrun
+    }
+
+    pub fn repeat(&self) -> RepeatPolicy {
+        self.repeat.unwrap_or_default()
+    }
+
+    pub fn goal(&self) -> Option<ClockDuration> {
+        self.goal
+    }
+
+    pub fn cap(&self) -> Option<ClockDuration> {
+        self.cap
+    }
+
+    pub fn alarm(&self) -> Result<Option<UtcDateTime>, InvalidDateFormat> {
+        self.alarm
+            .map(|date| local_date::to_utc_in_clock_zone(date, self.time_zone))
+            .transpose()
+    }
+
+    pub fn follow_pauses(&self) -> bool {
+        self.follow_pauses
+    }
+
+    pub fn pomodoro(&self) -> Option<PomodoroConfig> {
+        self.pomodoro.then(|| self.pomodoro_config.config())
+    }
+
+    pub fn group(&self) -> Option<String> {
+        self.group.clone()
+    }
+
+    pub fn time_zone(&self) -> Option<Tz> {
+        self.time_zone
+    }
+
+    pub fn speed(&self) -> Option<Speed> {
+        self.speed
+    }
+
+    pub fn budget(&self) -> Option<ClockDuration> {
+        self.budget
+    }
+
+    pub fn refill(&self) -> RefillPeriod {
+        self.refill.map(RefillPeriod::from).unwrap_or_default()
+    }
+
+    pub fn carry_over(&self) -> CarryOver {
+        self.carry_over.map(CarryOver::from).unwrap_or_default()
+    }
+
+    pub fn sequence(&self) -> Option<&[Stage]> {
+        (!self.sequence.is_empty()).then_some(self.sequence.as_slice())
     }
 }
 
-fn parse_start_date(s: &str) -> Result<UtcDateTime, InvalidStartDate> {
-    let valid_date: ParsedDate = s.parse()?;
-    let date: UtcDateTime = valid_date.into();
-    validate_if_date_is_not_in_future(&UtcTimeImpl, date)?;
-    let as_local = Local.from_local_datetime(&date.naive_utc()).unwrap();
-    let date = Utc.from_local_datetime(&as_local.naive_utc()).unwrap();
-    Ok(date)
+fn parse_repeat_policy(s: &str) -> Result<RepeatPolicy, ParseNonPosZeroError> {
+    if s == constants::REPEAT_FOREVER {
+        return Ok(RepeatPolicy::Forever);
+    }
+    let cycles: AtLeastOne = s.parse()?;
+    let cycles = cycles
+        .value()
+        .try_into()
+        .expect("At least one is never zero");
+    Ok(RepeatPolicy::Times(cycles))
 }
@@ -0,0 +1,29 @@
+use clap::ValueEnum;
+use corrupted_clock_util::timing::DurationStyle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum DurationStyleArg {
+    /// Whole days in front of the time of the day, like "1d 04:05:06"
+    #[default]
+    Days,
+    /// Whole weeks and days in front of the time of the day, like "1w 2d 04:05:06"
+    Weeks,
+    /// All hours in one field, like "28:05:06"
+    TotalHours,
+    /// Only the units which are not zero, like "1h 5m"
+    Short,
+    /// Hours with 2 decimal places, like "28.08h"
+    DecimalHours,
+}
+
+impl From<DurationStyleArg> for DurationStyle {
+    fn from(value: DurationStyleArg) -> Self {
+        match value {
+            DurationStyleArg::Days => DurationStyle::Days,
+            DurationStyleArg::Weeks => DurationStyle::Weeks,
+            DurationStyleArg::TotalHours => DurationStyle::TotalHours,
+            DurationStyleArg::Short => DurationStyle::Short,
+            DurationStyleArg::DecimalHours => DurationStyle::DecimalHours,
+        }
+    }
+}
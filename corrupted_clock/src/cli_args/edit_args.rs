@@ -0,0 +1,63 @@
+use clap::{Args, Subcommand};
+use corrupted_clock_util::{
+    parsed_date::{InvalidDateFormat, ParsedDate},
+    timing::{SegmentEdit, Tz},
+};
+
+use crate::AtLeastOne;
+
+use super::{local_date, ClockReference};
+
+#[derive(Debug, Args)]
+pub struct EditArgs {
+    #[command(flatten)]
+    reference: ClockReference,
+    #[command(subcommand)]
+    edit: EditSegmentCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum EditSegmentCommand {
+    /// Inserts a forgotten pause into a running segment
+    InsertPause { from: ParsedDate, to: ParsedDate },
+    /// Moves the start of a running segment
+    MoveStart { segment: AtLeastOne, to: ParsedDate },
+    /// Moves the end of a running segment.
+    /// Moving the end of the current running segment pauses the clock at this date.
+    MoveEnd { segment: AtLeastOne, to: ParsedDate },
+    /// Removes a running segment
+    Remove { segment: AtLeastOne },
+}
+
+impl EditArgs {
+    pub fn reference(&self) -> &ClockReference {
+        &self.reference
+    }
+
+    /// Resolves the dates of the edit in the time zone of the clock
+    pub fn segment_edit(&self, clock_zone: Option<Tz>) -> Result<SegmentEdit, InvalidDateFormat> {
+        fn to_index(segment: AtLeastOne) -> usize {
+            segment.value() as usize - 1
+        }
+        let to_utc = |date| local_date::to_utc_in_clock_zone(date, clock_zone);
+
+        let edit = match self.edit {
+            EditSegmentCommand::InsertPause { from, to } => SegmentEdit::InsertPause {
+                from: to_utc(from)?,
+                to: to_utc(to)?,
+            },
+            EditSegmentCommand::MoveStart { segment, to } => SegmentEdit::MoveStart {
+                segment: to_index(segment),
+                to: to_utc(to)?,
+            },
+            EditSegmentCommand::MoveEnd { segment, to } => SegmentEdit::MoveEnd {
+                segment: to_index(segment),
+                to: to_utc(to)?,
+            },
+            EditSegmentCommand::Remove { segment } => SegmentEdit::Remove {
+                segment: to_index(segment),
+            },
+        };
+        Ok(edit)
+    }
+}
@@ -0,0 +1,36 @@
+use clap::{Args, ValueEnum};
+use corrupted_clock_util::timing::ExclusiveMode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExclusiveModeArg {
+    /// Any number of clocks can run at once
+    Off,
+    /// Only one clock runs at a time
+    Global,
+    /// Only one clock per group runs at a time. Clocks without a group are not affected.
+    Group,
+}
+
+impl From<ExclusiveModeArg> for ExclusiveMode {
+    fn from(value: ExclusiveModeArg) -> Self {
+        match value {
+            ExclusiveModeArg::Off => ExclusiveMode::Off,
+            ExclusiveModeArg::Global => ExclusiveMode::Global,
+            ExclusiveModeArg::Group => ExclusiveMode::Group,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+/// Shows or sets the exclusive mode. While it is on, resuming or creating a clock
+/// pauses every other running clock in its scope.
+pub struct ExclusiveArgs {
+    /// Shows the current mode if not provided
+    mode: Option<ExclusiveModeArg>,
+}
+
+impl ExclusiveArgs {
+    pub fn mode(&self) -> Option<ExclusiveMode> {
+        self.mode.map(Into::into)
+    }
+}
@@ -1,6 +1,6 @@
 use clap::Args;
 
-use crate::AtLeastOne;
+use crate::{listing_items_param::TableFormat, AtLeastOne};
 
 use super::{ClockReference, ColumnShowArg};
 
@@ -10,6 +10,10 @@ pub struct GetClockArgs {
     reference: ClockReference,
     #[command(flatten)]
     column_num: ColumnShowArg,
+    #[arg(long)]
+    /// Lists the earlier runs of the clock with their count, average, best and worst passed time
+    /// instead of the clock itself. Runs are kept by the subcommand "reset" with option "archive".
+    runs: bool,
 }
 
 impl GetClockArgs {
@@ -20,4 +24,12 @@ impl GetClockArgs {
     pub fn column_num(&self) -> Option<AtLeastOne> {
         self.column_num.colums_num()
     }
+
+    pub fn table_format(&self) -> TableFormat {
+        self.column_num.table_format()
+    }
+
+    pub fn runs(&self) -> bool {
+        self.runs
+    }
 }
@@ -0,0 +1,26 @@
+use clap::Args;
+use corrupted_clock_util::timing::ClockDuration;
+
+#[derive(Debug, Args)]
+/// Sets or removes the goal of a stopwatch
+pub struct GoalArgs {
+    /// Name of the stopwatch
+    name: String,
+    #[arg(required_unless_present = "remove")]
+    /// Planned time budget. See the subcommand "create" for the syntax of a duration.
+    goal: Option<ClockDuration>,
+    #[arg(short, long, conflicts_with = "goal")]
+    /// Removes the goal
+    remove: bool,
+}
+
+impl GoalArgs {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// None if the goal is to be removed
+    pub fn goal(&self) -> Option<ClockDuration> {
+        self.goal
+    }
+}
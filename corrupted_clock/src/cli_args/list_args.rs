@@ -1,13 +1,19 @@
 use clap::Args;
+use corrupted_clock_util::timing::ClockState;
 
-use crate::AtLeastOne;
+use crate::{listing_items_param::TableFormat, AtLeastOne};
 
-use super::{ClockKind, ColumnShowArg};
+use super::{ClockKind, ClockStateArg, ColumnShowArg, SortBy};
 
 #[derive(Debug, Args)]
 pub struct ListArgs {
     #[arg(short, long)]
     kind: Option<ClockKind>,
+    #[arg(short, long)]
+    /// Only lists clocks in this state
+    state: Option<ClockStateArg>,
+    #[arg(long, default_value = "name")]
+    sort_by: SortBy,
     #[command(flatten)]
     colums_num: ColumnShowArg,
 }
@@ -17,7 +23,19 @@ impl ListArgs {
         self.kind
     }
 
+    pub fn state(&self) -> Option<ClockState> {
+        self.state.map(Into::into)
+    }
+
+    pub fn sort_by(&self) -> SortBy {
+        self.sort_by
+    }
+
     pub fn colums_num(&self) -> Option<AtLeastOne> {
         self.colums_num.colums_num()
     }
+
+    pub fn table_format(&self) -> TableFormat {
+        self.colums_num.table_format()
+    }
 }
@@ -0,0 +1,18 @@
+use chrono::Local;
+use corrupted_clock_util::{
+    parsed_date::{InvalidDateFormat, ParsedDate},
+    timing::{Tz, UtcDateTime},
+};
+
+/// Resolves a date given by the user in the time zone of its clock
+/// or in the local one of the user if the clock has no time zone.
+/// A time zone or UTC offset given explicitly at the end of the date takes precedence.
+pub fn to_utc_in_clock_zone(
+    date: ParsedDate,
+    clock_zone: Option<Tz>,
+) -> Result<UtcDateTime, InvalidDateFormat> {
+    match clock_zone {
+        Some(zone) => date.to_utc_in(&zone),
+        None => date.to_utc_in(&Local),
+    }
+}
@@ -1,4 +1,5 @@
 use clap::ValueEnum;
+use corrupted_clock_util::timing::ClockType;
 
 use crate::constants;
 
@@ -11,4 +12,24 @@ pub enum ManyClockReferenceKind {
     Stopwatch,
     #[value(alias(constants::COUNT_DOWN_ALIASE))]
     CountDown,
+    #[value(alias(constants::POMODORO_ALIASE))]
+    Pomodoro,
+    #[value(alias(constants::SEQUENCE_ALIASE))]
+    Sequence,
+    #[value(alias(constants::BUDGET_ALIASE))]
+    Budget,
+}
+
+impl ManyClockReferenceKind {
+    /// Kind of clocks which are referenced, none if all kinds are referenced
+    pub fn clock_type(self) -> Option<ClockType> {
+        match self {
+            ManyClockReferenceKind::All => None,
+            ManyClockReferenceKind::Stopwatch => Some(ClockType::Stopwatch),
+            ManyClockReferenceKind::CountDown => Some(ClockType::CountDown),
+            ManyClockReferenceKind::Pomodoro => Some(ClockType::Pomodoro),
+            ManyClockReferenceKind::Sequence => Some(ClockType::Sequence),
+            ManyClockReferenceKind::Budget => Some(ClockType::Budget),
+        }
+    }
 }
@@ -0,0 +1,35 @@
+use std::num::NonZeroU32;
+
+use clap::Args;
+use corrupted_clock_util::timing::{ClockDuration, PomodoroConfig};
+
+#[derive(Debug, Args)]
+/// Durations of the phases of a pomodoro.
+/// See option "to-count-down" for the syntax of a duration.
+pub struct PomodoroArgs {
+    #[arg(long, requires = "pomodoro")]
+    /// Duration of a work phase. Default: 25:00
+    work: Option<ClockDuration>,
+    #[arg(long, requires = "pomodoro")]
+    /// Duration of a short break. Default: 5:00
+    short_break: Option<ClockDuration>,
+    #[arg(long, requires = "pomodoro")]
+    /// Duration of a long break. Default: 15:00
+    long_break: Option<ClockDuration>,
+    #[arg(long, requires = "pomodoro")]
+    /// A long break follows after this many work phases. Default: 4
+    long_break_interval: Option<NonZeroU32>,
+}
+
+impl PomodoroArgs {
+    pub fn config(&self) -> PomodoroConfig {
+        let default = PomodoroConfig::default();
+        PomodoroConfig::new(
+            self.work.unwrap_or(default.work()),
+            self.short_break.unwrap_or(default.short_break()),
+            self.long_break.unwrap_or(default.long_break()),
+            self.long_break_interval
+                .unwrap_or(default.long_break_interval()),
+        )
+    }
+}
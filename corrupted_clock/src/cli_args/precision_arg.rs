@@ -0,0 +1,30 @@
+use clap::ValueEnum;
+use corrupted_clock_util::timing::Precision;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum PrecisionArg {
+    /// Whole seconds, like "00:01:05"
+    #[default]
+    #[value(alias("s"))]
+    Seconds,
+    /// Tenth of a second, like "00:01:05.3"
+    #[value(alias("ds"))]
+    Deciseconds,
+    /// Like "00:01:05.312"
+    #[value(alias("ms"))]
+    Milliseconds,
+    /// Like "00:01:05.312048"
+    #[value(alias("us"), alias("µs"))]
+    Microseconds,
+}
+
+impl From<PrecisionArg> for Precision {
+    fn from(value: PrecisionArg) -> Self {
+        match value {
+            PrecisionArg::Seconds => Precision::Seconds,
+            PrecisionArg::Deciseconds => Precision::Deciseconds,
+            PrecisionArg::Milliseconds => Precision::Milliseconds,
+            PrecisionArg::Microseconds => Precision::Microseconds,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use clap::ValueEnum;
+use corrupted_clock_util::timing::RefillPeriod;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RefillPeriodArg {
+    /// Refilled at every midnight
+    Daily,
+    /// Refilled at the midnight before every Monday
+    Weekly,
+    /// Refilled at the midnight before the first day of every month
+    Monthly,
+}
+
+impl From<RefillPeriodArg> for RefillPeriod {
+    fn from(value: RefillPeriodArg) -> Self {
+        match value {
+            RefillPeriodArg::Daily => RefillPeriod::Daily,
+            RefillPeriodArg::Weekly => RefillPeriod::Weekly,
+            RefillPeriodArg::Monthly => RefillPeriod::Monthly,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use clap::Args;
+
+use super::ExistingClockReference;
+
+#[derive(Debug, Args)]
+pub struct ResetArgs {
+    #[command(flatten)]
+    reference: ExistingClockReference,
+    #[arg(long)]
+    /// Keeps the run so far in the run history of the clock instead of discarding it.
+    /// See option "runs" of the subcommand "get" to list the runs.
+    archive: bool,
+}
+
+impl ResetArgs {
+    pub fn reference(&self) -> &ExistingClockReference {
+        &self.reference
+    }
+
+    pub fn archive(&self) -> bool {
+        self.archive
+    }
+}
@@ -0,0 +1,42 @@
+use clap::Args;
+use corrupted_clock_util::timing::{ClockDuration, TimeJumpPolicy};
+
+use super::TimeJumpPolicyArg;
+
+#[derive(Debug, Args)]
+/// Shows the settings or changes them
+pub struct SettingsArgs {
+    #[arg(long)]
+    /// Cap of new stopwatches which are created without the option "cap".
+    /// See the subcommand "create" for the syntax of a duration.
+    default_cap: Option<ClockDuration>,
+    #[arg(long, conflicts_with = "default_cap")]
+    /// New stopwatches are created without a cap
+    remove_default_cap: bool,
+    #[arg(long)]
+    /// How clocks are evaluated once the system time lies before their last recorded moment,
+    /// for example after the system time was set back
+    time_jump_policy: Option<TimeJumpPolicyArg>,
+}
+
+/// Change of a setting which can also be removed
+pub enum SettingChange<T> {
+    Set(T),
+    Remove,
+}
+
+impl SettingsArgs {
+    /// None if the default cap is not to be changed
+    pub fn default_cap(&self) -> Option<SettingChange<ClockDuration>> {
+        match (self.default_cap, self.remove_default_cap) {
+            (Some(cap), _) => Some(SettingChange::Set(cap)),
+            (None, true) => Some(SettingChange::Remove),
+            (None, false) => None,
+        }
+    }
+
+    /// None if the policy is not to be changed
+    pub fn time_jump_policy(&self) -> Option<TimeJumpPolicy> {
+        self.time_jump_policy.map(Into::into)
+    }
+}
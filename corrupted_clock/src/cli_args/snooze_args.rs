@@ -0,0 +1,41 @@
+use clap::Args;
+use corrupted_clock_util::timing::ClockDuration;
+
+use super::ExistingClockReference;
+
+#[derive(Debug, Args)]
+#[group(id = "change", required = true, multiple = false, args = ["by", "to"])]
+/// Extends or shortens count downs while they run or are paused
+pub struct SnoozeArgs {
+    #[command(flatten)]
+    reference: ExistingClockReference,
+    #[arg(short, long, allow_hyphen_values = true, value_parser = ClockDuration::parse_signed)]
+    /// Syntax: [+|-]<duration>
+    ///
+    /// Extends the count down by the duration. A leading "-" shortens it.
+    /// See the subcommand "create" for the syntax of a duration.
+    by: Option<ClockDuration>,
+    #[arg(short, long)]
+    /// Sets the time the count down counts down from
+    to: Option<ClockDuration>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum CountDownTimeChange {
+    By(ClockDuration),
+    To(ClockDuration),
+}
+
+impl SnoozeArgs {
+    pub fn reference(&self) -> &ExistingClockReference {
+        &self.reference
+    }
+
+    pub fn change(&self) -> CountDownTimeChange {
+        match (self.by, self.to) {
+            (Some(amount), _) => CountDownTimeChange::By(amount),
+            (None, Some(time)) => CountDownTimeChange::To(time),
+            (None, None) => unreachable!("Clap requires either option by or to"),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use clap::Args;
+use corrupted_clock_util::timing::Speed;
+
+use super::ClockReference;
+
+#[derive(Debug, Args)]
+/// Changes how fast the passed time of a clock grows compared to the real time.
+/// Time passed so far keeps the speeds it passed with.
+pub struct SpeedArgs {
+    #[command(flatten)]
+    reference: ClockReference,
+    /// Factor as decimal number or fraction. 1 is real time.
+    ///
+    /// # Examples
+    ///
+    /// 10 game minutes per real minute: 10
+    ///
+    /// Half as fast as real time: 0.5
+    ///
+    /// One minute per 3 real minutes: 1/3
+    speed: Speed,
+}
+
+impl SpeedArgs {
+    pub fn reference(&self) -> &ClockReference {
+        &self.reference
+    }
+
+    pub fn speed(&self) -> Speed {
+        self.speed
+    }
+}
@@ -0,0 +1,26 @@
+use corrupted_clock_util::timing::{ClockDuration, InvalidClockDurationString, Stage};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum InvalidStage {
+    #[error("Stage must be written as <label>=<duration>")]
+    MissingSeparator,
+    #[error("Label of a stage must not be empty")]
+    EmptyLabel,
+    #[error("Duration of stage '{0}' is invalid: {1}")]
+    InvalidDuration(String, InvalidClockDurationString),
+}
+
+/// Parses one stage of a sequence like "prep=2:00"
+pub fn parse_stage(s: &str) -> Result<Stage, InvalidStage> {
+    let (label, duration) = s.split_once('=').ok_or(InvalidStage::MissingSeparator)?;
+    let label = label.trim();
+    if label.is_empty() {
+        return Err(InvalidStage::EmptyLabel);
+    }
+    let duration: ClockDuration = duration
+        .trim()
+        .parse()
+        .map_err(|error| InvalidStage::InvalidDuration(label.to_string(), error))?;
+    Ok(Stage::new(label.to_string(), duration))
+}
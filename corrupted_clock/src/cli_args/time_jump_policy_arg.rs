@@ -0,0 +1,23 @@
+use clap::ValueEnum;
+use corrupted_clock_util::timing::TimeJumpPolicy;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TimeJumpPolicyArg {
+    /// Clocks are evaluated at their last recorded moment until the system time catches up
+    Clamp,
+    /// Clocks are evaluated with the system time as it is.
+    /// Like with every policy, a warning is logged which is shown with RUST_LOG=warn.
+    Warn,
+    /// The jump is recorded as a correction and clocks continue from their last recorded moment
+    Record,
+}
+
+impl From<TimeJumpPolicyArg> for TimeJumpPolicy {
+    fn from(value: TimeJumpPolicyArg) -> Self {
+        match value {
+            TimeJumpPolicyArg::Clamp => TimeJumpPolicy::Clamp,
+            TimeJumpPolicyArg::Warn => TimeJumpPolicy::Warn,
+            TimeJumpPolicyArg::Record => TimeJumpPolicy::Record,
+        }
+    }
+}
@@ -9,5 +9,9 @@ pub use env_var_prefix;
 
 pub const STOP_WATCH_ALIASE: &str = "sw";
 pub const COUNT_DOWN_ALIASE: &str = "cd";
+pub const POMODORO_ALIASE: &str = "pd";
+pub const SEQUENCE_ALIASE: &str = "sq";
+pub const BUDGET_ALIASE: &str = "bg";
 pub const ALL_CLOCK_ALIASE: &str = "a";
 pub const NOT_AVIABLE_TXT: &str = "N/A";
+pub const REPEAT_FOREVER: &str = "forever";
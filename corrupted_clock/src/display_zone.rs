@@ -0,0 +1,51 @@
+use corrupted_clock_util::timing::{Tz, UtcDateTime};
+
+/// Time zone in which dates are shown within the tables
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayZone {
+    /// Local time zone of the machine
+    #[default]
+    Local,
+    Utc,
+    Named(Tz),
+}
+
+impl DisplayZone {
+    pub fn new(utc: bool, named: Option<Tz>) -> Self {
+        match (utc, named) {
+            (true, _) => DisplayZone::Utc,
+            (false, Some(zone)) => DisplayZone::Named(zone),
+            (false, None) => DisplayZone::Local,
+        }
+    }
+
+    /// Zone of a clock is used unless another zone than the local one was chosen
+    pub fn or_clock_zone(self, clock_zone: Option<Tz>) -> Self {
+        match (self, clock_zone) {
+            (DisplayZone::Local, Some(zone)) => DisplayZone::Named(zone),
+            (chosen, _) => chosen,
+        }
+    }
+
+    pub fn date_to_str(self, date: UtcDateTime) -> String {
+        match self {
+            DisplayZone::Local => corrupted_clock_util::chrono_time_to_str(
+                corrupted_clock_util::convert_utc_to_local(date),
+            ),
+            DisplayZone::Utc => corrupted_clock_util::chrono_time_to_str(date),
+            DisplayZone::Named(zone) => {
+                corrupted_clock_util::chrono_time_to_str(date.with_timezone(&zone))
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for DisplayZone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DisplayZone::Local => f.write_str("local"),
+            DisplayZone::Utc => f.write_str("UTC"),
+            DisplayZone::Named(zone) => f.write_str(zone.name()),
+        }
+    }
+}
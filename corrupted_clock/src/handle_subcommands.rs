@@ -1,23 +1,34 @@
+mod cap_reached_err;
+mod not_a_count_down_err;
 mod not_found_clock_err;
+mod resume_all_exclusive_err;
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use corrupted_clock_util::{
     data_store,
-    timing::{ClockTable, CountDown, Stopwatch, TimeImpl, Timer as _, UtcTimeImpl},
+    timing::{
+        Clock, ClockState, ClockTable, ClockType, CountDown, ExclusiveMode, Stopwatch, TimeImpl,
+        Timer, UtcTimeImpl,
+    },
 };
 use log::{info, warn};
 
 use crate::{
     cli_args::{
-        AppCliArgs, ClockKind, CreateCommand, ExistingClockKindReference, GetClockArgs, ListArgs,
-        ManyClockReferenceKind,
+        AdjustArgs, AppCliArgs, CapArgs, ClockKind, ClockReference, CountDownTimeChange,
+        CreateCommand, EditArgs, ExclusiveArgs, ExistingClockKindReference, GetClockArgs, GoalArgs,
+        ListArgs, ManyClockReferenceKind, SettingChange, SettingsArgs, SnoozeArgs, SortBy,
+        SpeedArgs,
     },
-    listing_items_param::ListingItemsParams,
+    listing_items_param::{ListingItemsParams, TableFormat},
     path_utils, table_drawing, AppResult,
 };
 
-use self::not_found_clock_err::NotFoundClockErr;
+use self::{
+    cap_reached_err::CapReachedErr, not_a_count_down_err::NotACountDownErr,
+    not_found_clock_err::NotFoundClockErr, resume_all_exclusive_err::ResumeAllExclusiveErr,
+};
 
 pub fn create(general_args: &AppCliArgs, args: &CreateCommand) -> AppResult {
     let LoadedAppStateFile {
@@ -37,42 +48,315 @@ pub fn create(general_args: &AppCliArgs, args: &CreateCommand) -> AppResult {
             );
             name
         });
-    let opt_start_date = args.start_date();
-    match args.to_count_down() {
-        Some(count_down) => {
-            info!("Stopwatch under the name '{}' is created", name);
-            let count_down = if let Some(start_date) = opt_start_date {
-                CountDown::new_with_start(count_down, start_date)?
-            } else {
-                CountDown::new(count_down)
-            };
-            app_state.add_count_down(name, count_down)?;
+    let start = args.start_date()?;
+    let mut clock: Clock = if let Some(at) = args.alarm()? {
+        CountDown::new_alarm(at, args.follow_pauses())?.into()
+    } else if let Some(config) = args.pomodoro() {
+        Clock::new_pomodoro(config, start)
+    } else if let Some(stages) = args.sequence() {
+        Clock::new_sequence(stages.to_vec(), start)
+    } else if let Some(allowance) = args.budget() {
+        Clock::new_budget(allowance, args.refill(), args.carry_over(), start)
+    } else if let Some(time) = args.to_count_down() {
+        Clock::new_count_down(time, start)
+    } else {
+        Clock::new_stopwatch(start)
+    };
+    if let Some(count_down) = clock.as_mut_count_down() {
+        count_down.set_overrun(args.overrun());
+        count_down.set_repeat_policy(args.repeat());
+    }
+    if let Some(stopwatch) = clock.as_mut_stopwatch() {
+        stopwatch.set_goal(args.goal());
+        stopwatch.set_cap(args.cap().or(app_state.settings().default_cap()));
+    }
+    let settings = clock.mut_stopwatch();
+    settings.set_group(args.group());
+    settings.set_time_zone(args.time_zone());
+    if let Some(speed) = args.speed() {
+        settings.set_speed(speed);
+    }
+    let clock_type = clock.clock_type();
+    app_state.add(name.clone(), clock)?;
+    info!("The {} under the name '{}' is created", clock_type, name);
+    log_paused_clocks(app_state.pause_others(clock_type, &name));
+
+    save_app_state(&path_to_app_file, &mut app_state)
+}
+
+pub fn resume(general_args: &AppCliArgs, args: &ExistingClockKindReference) -> AppResult {
+    let LoadedAppStateFile {
+        mut app_state,
+        path_to_app_file,
+    } = load_app_state(general_args)?;
+    let exclusive_mode = app_state.settings().exclusive_mode();
+    if matches!(args, ExistingClockKindReference::All(_)) && exclusive_mode != ExclusiveMode::Off {
+        return Err(ResumeAllExclusiveErr(exclusive_mode).into());
+    }
+    resume_clocks(&mut app_state, args)?;
+    if let ExistingClockKindReference::Single(reference) = args {
+        log_paused_clocks(app_state.pause_others(reference.kind().into(), reference.name()));
+    }
+
+    save_app_state(&path_to_app_file, &mut app_state)
+}
+
+pub fn switch(general_args: &AppCliArgs, reference: &ClockReference) -> AppResult {
+    let LoadedAppStateFile {
+        mut app_state,
+        path_to_app_file,
+    } = load_app_state(general_args)?;
+    resume_clocks(
+        &mut app_state,
+        &ExistingClockKindReference::Single(reference.clone()),
+    )?;
+    let mode = match app_state.settings().exclusive_mode() {
+        ExclusiveMode::Off => ExclusiveMode::Global,
+        mode => mode,
+    };
+    log_paused_clocks(app_state.pause_others_with(mode, reference.kind().into(), reference.name()));
+
+    save_app_state(&path_to_app_file, &mut app_state)
+}
+
+pub fn exclusive(general_args: &AppCliArgs, args: &ExclusiveArgs) -> AppResult<Option<String>> {
+    let LoadedAppStateFile {
+        mut app_state,
+        path_to_app_file,
+    } = load_app_state(general_args)?;
+    match args.mode() {
+        Some(mode) => {
+            app_state.mut_settings().set_exclusive_mode(mode);
+            info!("Exclusive mode is set to \"{}\"", mode);
+            save_app_state(&path_to_app_file, &mut app_state).map(|_| None)
         }
-        None => {
-            info!("Count down under the name '{}' is created", name);
-            let stopwatch = if let Some(start_date) = opt_start_date {
-                Stopwatch::new_with_impl_and_start_date(UtcTimeImpl, start_date)?
-            } else {
-                Stopwatch::new()
-            };
-            app_state.add_stopwatch(name, stopwatch)?
+        None => Ok(Some(format!(
+            "Exclusive mode: {}",
+            app_state.settings().exclusive_mode()
+        ))),
+    }
+}
+
+pub fn settings(general_args: &AppCliArgs, args: &SettingsArgs) -> AppResult<Option<String>> {
+    let LoadedAppStateFile {
+        mut app_state,
+        path_to_app_file,
+    } = load_app_state(general_args)?;
+    let (default_cap, time_jump_policy) = (args.default_cap(), args.time_jump_policy());
+    if default_cap.is_none() && time_jump_policy.is_none() {
+        return Ok(Some(table_drawing::settings_rows(app_state.settings())));
+    }
+    if let Some(change) = default_cap {
+        let cap = match change {
+            SettingChange::Set(cap) => Some(cap),
+            SettingChange::Remove => None,
+        };
+        app_state.mut_settings().set_default_cap(cap);
+        match cap {
+            Some(cap) => info!("Default cap of new stop watches is set to {}", cap),
+            None => info!("Default cap of new stop watches was removed"),
         }
-    };
+    }
+    if let Some(policy) = time_jump_policy {
+        app_state.set_time_jump_policy(policy);
+        info!("Policy for jumps of the system time is set to {}", policy);
+    }
+    save_app_state(&path_to_app_file, &mut app_state).map(|_| None)
+}
 
-    data_store::save_app_state(&path_to_app_file, &app_state)?;
+/// A single stopwatch at its cap is not resumed but reported as an error.
+/// Stopwatches at their cap among all resumed clocks are only logged.
+fn resume_clocks(app_state: &mut ClockTable, args: &ExistingClockKindReference) -> AppResult {
+    if let ExistingClockKindReference::Single(reference) = args {
+        let name = reference.name();
+        let capped = app_state
+            .get(reference.kind().into(), name)
+            .and_then(Clock::as_stopwatch)
+            .filter(|stopwatch| stopwatch.reached_cap())
+            .and_then(Stopwatch::cap);
+        if let Some(cap) = capped {
+            return Err(CapReachedErr::new(name.to_owned(), cap).into());
+        }
+    }
+    modify_clocks(app_state, args, |clock| clock.resume())?;
+    if let ExistingClockKindReference::All(kind) = args {
+        if kind
+            .clock_type()
+            .is_none_or(|clock_type| clock_type == ClockType::Stopwatch)
+        {
+            for (name, _) in app_state
+                .all_stopwatches()
+                .filter(|(_, stopwatch)| stopwatch.reached_cap())
+            {
+                warn!(
+                    "The stopwatch with name `{}` stays stopped at its cap. Raise it with the subcommand \"cap\"",
+                    name
+                );
+            }
+        }
+    }
     Ok(())
 }
 
-pub fn resume(general_args: &AppCliArgs, args: &ExistingClockKindReference) -> AppResult {
-    handle_modify_with_save(general_args, args, |sw| sw.resume(), |sw| sw.resume())
+fn log_paused_clocks(paused: Vec<(ClockType, String)>) {
+    for (clock_type, name) in paused {
+        info!(
+            "The {} with name `{}` was paused by the exclusive mode",
+            clock_type, name
+        );
+    }
 }
 
-pub fn reset(general_args: &AppCliArgs, args: &ExistingClockKindReference) -> AppResult {
-    handle_modify_with_save(general_args, args, |sw| sw.reset(), |cd| cd.reset())
+pub fn reset(
+    general_args: &AppCliArgs,
+    args: &ExistingClockKindReference,
+    archive: bool,
+) -> AppResult {
+    handle_modify_with_save(general_args, args, |clock| {
+        if archive {
+            clock.reset_archived()
+        } else {
+            clock.reset()
+        }
+    })
 }
 
 pub fn pause(general_args: &AppCliArgs, args: &ExistingClockKindReference) -> AppResult {
-    handle_modify_with_save(general_args, args, |sw| sw.pause(), |cd| cd.pause())
+    handle_modify_with_save(general_args, args, |clock| clock.pause())
+}
+
+pub fn lap(general_args: &AppCliArgs, args: &ExistingClockKindReference) -> AppResult {
+    handle_modify_with_save(general_args, args, |clock| _ = clock.lap())
+}
+
+pub fn edit(general_args: &AppCliArgs, args: &EditArgs) -> AppResult {
+    let LoadedAppStateFile {
+        mut app_state,
+        path_to_app_file,
+    } = load_app_state(general_args)?;
+    let reference = args.reference();
+    let (name, kind) = (reference.name(), reference.kind());
+    let clock = app_state
+        .get_mut(kind.into(), name)
+        .ok_or_else(|| NotFoundClockErr::new(name.to_owned(), kind))?;
+    let segment_edit = args.segment_edit(clock.stopwatch().time_zone())?;
+    clock.edit(segment_edit)?;
+    info!(
+        "Running segments of the {} with name `{}` were edited",
+        kind, name
+    );
+
+    save_app_state(&path_to_app_file, &mut app_state)
+}
+
+pub fn adjust(general_args: &AppCliArgs, args: &AdjustArgs) -> AppResult {
+    let LoadedAppStateFile {
+        mut app_state,
+        path_to_app_file,
+    } = load_app_state(general_args)?;
+    let reference = args.reference();
+    let (name, kind) = (reference.name(), reference.kind());
+    let (amount, reason) = (args.amount(), args.reason().map(ToOwned::to_owned));
+    app_state
+        .get_mut(kind.into(), name)
+        .ok_or_else(|| NotFoundClockErr::new(name.to_owned(), kind))?
+        .adjust(amount, reason);
+    info!(
+        "Passed time of the {} with name `{}` was adjusted",
+        kind, name
+    );
+
+    save_app_state(&path_to_app_file, &mut app_state)
+}
+
+pub fn speed(general_args: &AppCliArgs, args: &SpeedArgs) -> AppResult {
+    let LoadedAppStateFile {
+        mut app_state,
+        path_to_app_file,
+    } = load_app_state(general_args)?;
+    let reference = args.reference();
+    let (name, kind) = (reference.name(), reference.kind());
+    app_state
+        .get_mut(kind.into(), name)
+        .ok_or_else(|| NotFoundClockErr::new(name.to_owned(), kind))?
+        .mut_stopwatch()
+        .set_speed(args.speed());
+    info!(
+        "Speed of the {} with name `{}` is set to {}",
+        kind,
+        name,
+        args.speed()
+    );
+
+    save_app_state(&path_to_app_file, &mut app_state)
+}
+
+pub fn goal(general_args: &AppCliArgs, args: &GoalArgs) -> AppResult {
+    let LoadedAppStateFile {
+        mut app_state,
+        path_to_app_file,
+    } = load_app_state(general_args)?;
+    let name = args.name();
+    let stopwatch = app_state
+        .mut_stopwatch(name)
+        .ok_or_else(|| NotFoundClockErr::new(name.to_owned(), ClockKind::StopWatch))?;
+    stopwatch.set_goal(args.goal());
+    match args.goal() {
+        Some(goal) => info!("Goal of the stop watch `{}` is set to {}", name, goal),
+        None => info!("Goal of the stop watch `{}` was removed", name),
+    }
+
+    save_app_state(&path_to_app_file, &mut app_state)
+}
+
+pub fn cap(general_args: &AppCliArgs, args: &CapArgs) -> AppResult {
+    let LoadedAppStateFile {
+        mut app_state,
+        path_to_app_file,
+    } = load_app_state(general_args)?;
+    let name = args.name();
+    let stopwatch = app_state
+        .mut_stopwatch(name)
+        .ok_or_else(|| NotFoundClockErr::new(name.to_owned(), ClockKind::StopWatch))?;
+    stopwatch.set_cap(args.cap());
+    match args.cap() {
+        Some(cap) => info!("Cap of the stop watch `{}` is set to {}", name, cap),
+        None => info!("Cap of the stop watch `{}` was removed", name),
+    }
+
+    save_app_state(&path_to_app_file, &mut app_state)
+}
+
+pub fn snooze(general_args: &AppCliArgs, args: &SnoozeArgs) -> AppResult {
+    let LoadedAppStateFile {
+        mut app_state,
+        path_to_app_file,
+    } = load_app_state(general_args)?;
+    let change = |count_down: &mut CountDown| match args.change() {
+        CountDownTimeChange::By(amount) => count_down.extend_by(amount),
+        CountDownTimeChange::To(time) => count_down.set_count_down_time(time),
+    };
+    match args.reference().kind_reference()? {
+        ExistingClockKindReference::All(
+            ManyClockReferenceKind::All | ManyClockReferenceKind::CountDown,
+        ) => {
+            app_state.mut_all_count_downs().for_each(change);
+            info!("Time of all count downs was changed");
+        }
+        ExistingClockKindReference::Single(reference)
+            if reference.kind() == ClockKind::CountDown =>
+        {
+            let name = reference.name();
+            let count_down = app_state
+                .mut_count_down(name)
+                .ok_or_else(|| NotFoundClockErr::new(name.to_owned(), ClockKind::CountDown))?;
+            change(count_down);
+            info!("Time of the count down with name `{}` was changed", name);
+        }
+        _ => return Err(NotACountDownErr.into()),
+    }
+
+    save_app_state(&path_to_app_file, &mut app_state)
 }
 
 pub fn delete(general_args: &AppCliArgs, args: ExistingClockKindReference) -> AppResult {
@@ -82,77 +366,142 @@ pub fn delete(general_args: &AppCliArgs, args: ExistingClockKindReference) -> Ap
     } = load_app_state(general_args)?;
     match args {
         ExistingClockKindReference::All(kind) => {
-            let (remove_sws, remove_cds) = match kind {
-                ManyClockReferenceKind::All => (true, true),
-                ManyClockReferenceKind::Stopwatch => (true, false),
-                ManyClockReferenceKind::CountDown => (false, true),
-            };
-            if remove_sws {
-                app_state.remove_all_stopwatches();
-                info!("All stop watches were removed");
-            }
-            if remove_cds {
-                app_state.remove_all_count_donws();
-                info!("All count downs were removed");
+            let clock_type = kind.clock_type();
+            app_state.remove_all(clock_type);
+            match clock_type {
+                Some(clock_type) => info!("All clocks of the kind {} were removed", clock_type),
+                None => info!("All clocks were removed"),
             }
         }
         ExistingClockKindReference::Single(single) => {
-            let name = single.name();
-            let kind = single.kind();
-            match kind {
-                ClockKind::StopWatch => {
-                    if !app_state.has_stop_watch(name) {
-                        return Err(NotFoundClockErr::new(name.to_owned(), kind).into());
-                    } else {
-                        app_state.remove_stopwatch(name);
-                        info!("Stop watch under the name '{}' was removed", name);
-                    }
-                }
-                ClockKind::CountDown => {
-                    if !app_state.has_count_down(name) {
-                        return Err(NotFoundClockErr::new(name.to_owned(), kind).into());
-                    } else {
-                        app_state.remove_count_down(name);
-                        info!("Count down under the name '{}' was removed", name);
-                    }
-                }
+            let (name, kind) = (single.name(), single.kind());
+            if !app_state.remove(kind.into(), name) {
+                return Err(NotFoundClockErr::new(name.to_owned(), kind).into());
             }
+            info!("The {} under the name '{}' was removed", kind, name);
         }
     }
 
-    data_store::save_app_state(&path_to_app_file, &app_state)?;
-    Ok(())
+    save_app_state(&path_to_app_file, &mut app_state)
 }
 
 pub fn list(general_args: &AppCliArgs, args: &ListArgs) -> AppResult<String> {
     let LoadedAppStateFile { app_state, .. } = load_app_state(general_args)?;
 
-    let output = draw_tables_of_cds_sws(&app_state, args.kind(), args.into());
+    let output = draw_tables_of_cds_sws(
+        &app_state,
+        args.kind(),
+        args.state(),
+        args.sort_by(),
+        args.into(),
+    );
     Ok(output)
 }
 
 pub fn get_clock(general_args: &AppCliArgs, args: &GetClockArgs) -> AppResult<String> {
     let LoadedAppStateFile { app_state, .. } = load_app_state(general_args)?;
     let referecne = args.reference();
-    let name = referecne.name();
-    match referecne.kind() {
-        ClockKind::CountDown => {
-            let count_down = app_state
-                .get_count_down(name)
-                .ok_or_else(|| NotFoundClockErr::new(name.to_owned(), ClockKind::CountDown))?;
-            let table =
-                table_drawing::count_down_rows(args.into(), [(name, count_down)].into_iter());
-            Ok(table.to_string())
+    let (name, kind) = (referecne.name(), referecne.kind());
+    let clock = app_state
+        .get(kind.into(), name)
+        .ok_or_else(|| NotFoundClockErr::new(name.to_owned(), kind))?;
+    let params = ListingItemsParams::from(args).with_clock_zone(clock.stopwatch().time_zone());
+    let format = params.format();
+    if args.runs() {
+        return Ok(format!(
+            "Runs\n\
+            {}\n\
+            Statistics\n\
+            {}",
+            table_drawing::run_rows(clock.stopwatch().runs(), format),
+            table_drawing::run_stats_rows(clock.stopwatch().runs(), format)
+        ));
+    }
+    let mut table = table_drawing::clock_rows(params, kind.into(), [(name, clock)]);
+    match clock {
+        Clock::CountDown(count_down) if !count_down.time_changes().is_empty() => {
+            table.push_str(&format!(
+                "\n\
+                Time changes\n\
+                {}",
+                table_drawing::time_change_rows(count_down.time_changes(), format)
+            ));
+        }
+        Clock::Pomodoro(pomodoro) if !pomodoro.phase_history().is_empty() => {
+            table.push_str(&format!(
+                "\n\
+                Finished phases\n\
+                {}",
+                table_drawing::phase_rows(&pomodoro.phase_history(), format)
+            ));
+        }
+        Clock::Sequence(sequence) => {
+            table.push_str(&format!(
+                "\n\
+                Stages\n\
+                {}",
+                table_drawing::stage_rows(&sequence.stage_progress(), format)
+            ));
         }
-        ClockKind::StopWatch => {
-            let stop_watch = app_state
-                .get_stopwatch(name)
-                .ok_or_else(|| NotFoundClockErr::new(name.to_owned(), ClockKind::StopWatch))?;
-            let table =
-                table_drawing::stop_watch_rows(args.into(), [(name, stop_watch)].into_iter());
-            Ok(table.to_string())
+        Clock::Budget(budget) if !budget.periods().is_empty() => {
+            table.push_str(&format!(
+                "\n\
+                Periods\n\
+                {}",
+                table_drawing::period_rows(&budget.periods(), format)
+            ));
         }
+        _ => (),
+    }
+    Ok(with_detail_tables(table, clock, format))
+}
+
+fn with_detail_tables(mut table: String, clock: &Clock, format: TableFormat) -> String {
+    let segments =
+        table_drawing::segment_rows(&clock.running_segments(), UtcTimeImpl.now(), format);
+    table.push_str(&format!(
+        "\n\
+        Running segments\n\
+        {}",
+        segments
+    ));
+    let adjustments = clock.adjustments();
+    if !adjustments.is_empty() {
+        table.push_str(&format!(
+            "\n\
+            Adjustments\n\
+            {}",
+            table_drawing::adjustment_rows(adjustments, format)
+        ));
+    }
+    let rate_changes = clock.stopwatch().rate_changes();
+    if !rate_changes.is_empty() {
+        table.push_str(&format!(
+            "\n\
+            Rate changes\n\
+            {}",
+            table_drawing::rate_change_rows(rate_changes, format)
+        ));
+    }
+    let time_jumps = clock.stopwatch().time_jumps();
+    if !time_jumps.is_empty() {
+        table.push_str(&format!(
+            "\n\
+            Time jumps\n\
+            {}",
+            table_drawing::time_jump_rows(time_jumps, format)
+        ));
     }
+    let laps = clock.laps();
+    if !laps.is_empty() {
+        table.push_str(&format!(
+            "\n\
+            Laps\n\
+            {}",
+            table_drawing::lap_rows(laps, format)
+        ));
+    }
+    table
 }
 
 struct LoadedAppStateFile {
@@ -170,118 +519,98 @@ fn load_app_state(general_args: &AppCliArgs) -> AppResult<LoadedAppStateFile> {
         );
         ClockTable::default()
     });
+    for (clock_type, name, jump) in app_state.time_jumps() {
+        warn!(
+            "System time lies {} before the last recorded moment of the {} with name `{}`. \
+            It was probably set back. Policy for this case: {}",
+            jump.backwards_by(),
+            clock_type,
+            name,
+            app_state.settings().time_jump_policy()
+        );
+    }
     Ok(LoadedAppStateFile {
         path_to_app_file: app_state_file,
         app_state,
     })
 }
 
+/// Records the finished phases of pomodoros, stops stopwatches at their cap,
+/// notes the last moment seen to detect jumps of the system time
+/// and closes the refilled periods of budgets before the clocks are persisted
+fn save_app_state(path_to_app_file: &Path, app_state: &mut ClockTable) -> AppResult {
+    app_state.refresh();
+    data_store::save_app_state(path_to_app_file, app_state)?;
+    Ok(())
+}
+
 fn handle_modify_with_save(
     general_args: &AppCliArgs,
     reference: &ExistingClockKindReference,
-    mut on_stopwatch: impl FnMut(&mut Stopwatch),
-    mut on_count_down: impl FnMut(&mut CountDown),
+    on_clock: impl FnMut(&mut Clock),
 ) -> AppResult {
     let LoadedAppStateFile {
         mut app_state,
         path_to_app_file,
     } = load_app_state(general_args)?;
+    modify_clocks(&mut app_state, reference, on_clock)?;
+
+    save_app_state(&path_to_app_file, &mut app_state)
+}
+
+fn modify_clocks(
+    app_state: &mut ClockTable,
+    reference: &ExistingClockKindReference,
+    mut on_clock: impl FnMut(&mut Clock),
+) -> AppResult {
     match reference {
         ExistingClockKindReference::All(kind) => {
-            let (on_sws, on_cds) = match kind {
-                ManyClockReferenceKind::All => (true, true),
-                ManyClockReferenceKind::Stopwatch => (true, false),
-                ManyClockReferenceKind::CountDown => (false, true),
-            };
-            if on_sws {
-                for stopwatch in app_state.mut_all_stop_watches() {
-                    on_stopwatch(stopwatch);
-                }
-                info!("Modification was done on all stop watches");
-            }
-            if on_cds {
-                for count_down in app_state.mut_all_count_downs() {
-                    on_count_down(count_down);
-                }
-                info!("Modification was done on all count downs");
+            let clock_type = kind.clock_type();
+            app_state.mut_all(clock_type).for_each(on_clock);
+            match clock_type {
+                Some(clock_type) => info!(
+                    "Modification was done on all clocks of the kind {}",
+                    clock_type
+                ),
+                None => info!("Modification was done on all clocks"),
             }
         }
         ExistingClockKindReference::Single(reference) => {
-            let name = reference.name();
-            match reference.kind() {
-                ClockKind::StopWatch => match app_state.mut_stopwatch(name) {
-                    Some(sw) => {
-                        info!(
-                            "Modification was done on the stop watch with name `{}`",
-                            name
-                        );
-                        on_stopwatch(sw)
-                    }
-                    None => {
-                        return Err(NotFoundClockErr::new(name.to_owned(), reference.kind()).into())
-                    }
-                },
-                ClockKind::CountDown => match app_state.mut_count_down(name) {
-                    Some(sw) => {
-                        info!(
-                            "Modification was done on the count down with name `{}`",
-                            name
-                        );
-                        on_count_down(sw)
-                    }
-                    None => {
-                        return Err(NotFoundClockErr::new(name.to_owned(), reference.kind()).into())
-                    }
-                },
-            }
+            let (name, kind) = (reference.name(), reference.kind());
+            let clock = app_state
+                .get_mut(kind.into(), name)
+                .ok_or_else(|| NotFoundClockErr::new(name.to_owned(), kind))?;
+            on_clock(clock);
+            info!("Modification was done on the {} with name `{}`", kind, name);
         }
     }
-
-    data_store::save_app_state(&path_to_app_file, &app_state)?;
     Ok(())
 }
 
 fn draw_tables_of_cds_sws<T>(
     app_state: &ClockTable<T>,
     clock_kind: Option<ClockKind>,
+    state: Option<ClockState>,
+    sort_by: SortBy,
     list_params: ListingItemsParams,
 ) -> String
 where
     T: Default + TimeImpl,
 {
-    let (does_stopwatches, does_count_downs) = match clock_kind {
-        Some(ClockKind::CountDown) => (false, true),
-        Some(ClockKind::StopWatch) => (true, false),
-        None => (true, true),
+    let clock_types = match clock_kind {
+        Some(kind) => vec![kind.into()],
+        None => ClockType::ALL.to_vec(),
     };
     let mut output = String::default();
 
-    if does_stopwatches {
-        let stop_watches = {
-            let mut to_sort: Vec<_> = app_state.all_stopwatches().collect();
-            to_sort.sort_by(|(l_key, _), (r_key, _)| l_key.cmp(&r_key));
-            to_sort
-        };
-        let sw_table =
-            table_drawing::stop_watch_rows(list_params.clone(), stop_watches).to_string();
-        let to_push = format!(
-            "Stopwatches\n\
-            {}\n",
-            sw_table
-        );
-        output.push_str(&to_push);
-    }
-    if does_count_downs {
-        let count_downs = {
-            let mut to_sort: Vec<_> = app_state.all_count_downs().collect();
-            to_sort.sort_by(|(l_key, _), (r_key, _)| l_key.cmp(&r_key));
-            to_sort
-        };
-        let cd_table = table_drawing::count_down_rows(list_params, count_downs).to_string();
+    for clock_type in clock_types {
+        let clocks = select_clocks(app_state.all(Some(clock_type)), state, sort_by);
+        let table = table_drawing::clock_rows(list_params.clone(), clock_type, clocks);
         let to_push = format!(
-            "Countdowns\n\
+            "{}\n\
             {}\n",
-            cd_table
+            table_drawing::clock_type_title(clock_type),
+            table
         );
         output.push_str(&to_push);
     }
@@ -289,16 +618,35 @@ where
     output
 }
 
+fn select_clocks<'a, C: Timer>(
+    clocks: impl Iterator<Item = (&'a str, &'a C)>,
+    state: Option<ClockState>,
+    sort_by: SortBy,
+) -> Vec<(&'a str, &'a C)> {
+    let mut selected: Vec<_> = clocks
+        .filter(|(_, clock)| state.is_none_or(|state| clock.state() == state))
+        .collect();
+    match sort_by {
+        SortBy::Name => selected.sort_by_key(|(name, _)| *name),
+        SortBy::State => selected.sort_by_key(|(name, clock)| (clock.state(), *name)),
+    }
+    selected
+}
+
 #[cfg(test)]
 mod testing {
     use std::collections::HashMap;
 
     use chrono::{TimeDelta, TimeZone, Utc};
     use corrupted_clock_util::timing::{
-        mocking_time::MockTimeImpl, ClockTable, CountDown, Stopwatch, Timer as _,
+        mocking_time::MockTimeImpl, ClockDuration, ClockState, ClockTable, CountDown, Stopwatch,
+        Timer as _,
     };
 
-    use crate::{cli_args::ClockKind, handle_subcommands::draw_tables_of_cds_sws};
+    use crate::{
+        cli_args::{ClockKind, ClockReference, ExistingClockKindReference, SortBy},
+        handle_subcommands::{draw_tables_of_cds_sws, resume_clocks},
+    };
 
     #[test]
     fn draw_tables_for_list_subcommand() {
@@ -307,7 +655,8 @@ mod testing {
             clock_kind: Option<ClockKind>,
             input: ClockTable<MockTimeImpl>,
         ) {
-            let actual = draw_tables_of_cds_sws(&input, clock_kind, Default::default());
+            let actual =
+                draw_tables_of_cds_sws(&input, clock_kind, None, SortBy::Name, Default::default());
             insta::assert_snapshot!(case_name, actual);
         }
 
@@ -334,4 +683,66 @@ mod testing {
         let data: ClockTable<MockTimeImpl> = ClockTable::new(stopwatches, count_downs);
         assert_case("Drawing table from stopwatches or count downs", None, data);
     }
+
+    #[test]
+    fn draw_count_downs_filtered_and_sorted_by_state() {
+        let time = MockTimeImpl::new(Utc.with_ymd_and_hms(2024, 5, 1, 8, 20, 40).unwrap());
+        let ten_minutes = ClockDuration::new_secs_mins_hours(None, Some(10), None);
+        let finished = CountDown::new_with_impl(time.clone(), ten_minutes);
+        let mut paused = CountDown::new_with_impl(time.clone(), ten_minutes);
+        time.add_to_now(TimeDelta::minutes(5));
+        paused.pause();
+        let running = CountDown::new_with_impl(time.clone(), ten_minutes);
+        time.add_to_now(TimeDelta::minutes(7));
+        let count_downs: HashMap<String, CountDown<MockTimeImpl>> = [
+            ("a finished".to_string(), finished),
+            ("b paused".to_string(), paused),
+            ("c running".to_string(), running),
+        ]
+        .into();
+        let data = ClockTable::new(Default::default(), count_downs);
+
+        let sorted = draw_tables_of_cds_sws(
+            &data,
+            Some(ClockKind::CountDown),
+            None,
+            SortBy::State,
+            Default::default(),
+        );
+        insta::assert_snapshot!(sorted);
+        let only_finished = draw_tables_of_cds_sws(
+            &data,
+            Some(ClockKind::CountDown),
+            Some(ClockState::Finished),
+            SortBy::Name,
+            Default::default(),
+        );
+        insta::assert_snapshot!(only_finished);
+    }
+
+    #[test]
+    fn report_resuming_stopwatch_at_its_cap() {
+        let mut stopwatch = Stopwatch::new_with_start_date(Utc::now() - TimeDelta::hours(2))
+            .expect("Start date lies in the past");
+        stopwatch.set_cap(Some(ClockDuration::new_secs_mins_hours(
+            Some(1),
+            None,
+            None,
+        )));
+        stopwatch.pause();
+        let mut app_state = ClockTable::default();
+        app_state
+            .add("focus".to_string(), stopwatch)
+            .expect("Table is empty");
+        let reference = ExistingClockKindReference::Single(ClockReference::new(
+            "focus".to_string(),
+            Some(ClockKind::StopWatch),
+        ));
+
+        let error = resume_clocks(&mut app_state, &reference).unwrap_err();
+
+        insta::assert_snapshot!(error.to_string());
+        let stopwatch = app_state.all_stopwatches().next().unwrap().1;
+        assert!(stopwatch.is_paused());
+    }
 }
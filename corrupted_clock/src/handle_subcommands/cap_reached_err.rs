@@ -0,0 +1,15 @@
+use corrupted_clock_util::timing::ClockDuration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+#[error("The stopwatch \"{name}\" reached its cap of {cap}. Raise it with the subcommand \"cap\" to resume it")]
+pub struct CapReachedErr {
+    name: String,
+    cap: ClockDuration,
+}
+
+impl CapReachedErr {
+    pub fn new(name: String, cap: ClockDuration) -> Self {
+        Self { name, cap }
+    }
+}
@@ -0,0 +1,5 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+#[error("Only the time of count downs can be changed. Use the kind \"count-down\" or \"cd\"")]
+pub struct NotACountDownErr;
@@ -0,0 +1,6 @@
+use corrupted_clock_util::timing::ExclusiveMode;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+#[error("Clocks can only be resumed one by one while the exclusive mode is \"{0}\"")]
+pub struct ResumeAllExclusiveErr(pub ExclusiveMode);
@@ -9,5 +9,6 @@ pub mod table_drawing;
 pub type AppError = Box<dyn std::error::Error>;
 pub type AppResult<T = ()> = Result<T, AppError>;
 
+mod display_zone;
 mod listing_items_param;
 mod non_zero;
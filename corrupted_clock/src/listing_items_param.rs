@@ -1,25 +1,79 @@
+use corrupted_clock_util::timing::{DurationFormat, Tz, UtcDateTime};
+
 use crate::{
     cli_args::{GetClockArgs, ListArgs},
+    display_zone::DisplayZone,
     AtLeastOne,
 };
 
 #[derive(Debug, Clone, Default)]
-pub struct ListingItemsParams(Option<AtLeastOne>);
+pub struct ListingItemsParams {
+    column_num: Option<AtLeastOne>,
+    format: TableFormat,
+}
 
 impl ListingItemsParams {
     pub fn column_num(&self) -> Option<AtLeastOne> {
-        self.0
+        self.column_num
+    }
+
+    pub fn format(&self) -> TableFormat {
+        self.format
+    }
+
+    /// Shows the dates in the zone of a clock unless another zone was chosen
+    pub fn with_clock_zone(mut self, clock_zone: Option<Tz>) -> Self {
+        self.format.zone = self.format.zone.or_clock_zone(clock_zone);
+        self
     }
 }
 
 impl From<&GetClockArgs> for ListingItemsParams {
     fn from(value: &GetClockArgs) -> Self {
-        Self(value.column_num())
+        Self {
+            column_num: value.column_num(),
+            format: value.table_format(),
+        }
     }
 }
 
 impl From<&ListArgs> for ListingItemsParams {
     fn from(value: &ListArgs) -> Self {
-        Self(value.colums_num())
+        Self {
+            column_num: value.colums_num(),
+            format: value.table_format(),
+        }
+    }
+}
+
+/// How durations and dates are written within the tables
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TableFormat {
+    duration: DurationFormat,
+    zone: DisplayZone,
+}
+
+impl TableFormat {
+    pub fn new(duration: DurationFormat, zone: DisplayZone) -> Self {
+        Self { duration, zone }
+    }
+
+    pub fn zone(&self) -> DisplayZone {
+        self.zone
+    }
+
+    pub fn date_to_str(&self, date: UtcDateTime) -> String {
+        self.zone.date_to_str(date)
+    }
+
+    /// Title of a date column which states the zone of its dates
+    pub fn date_header(&self, title: &str) -> String {
+        format!("{} ({})", title, self.zone)
+    }
+}
+
+impl From<TableFormat> for DurationFormat {
+    fn from(value: TableFormat) -> Self {
+        value.duration
     }
 }
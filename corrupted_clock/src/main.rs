@@ -28,7 +28,7 @@ fn main() -> ExitCode {
 fn subcommand(args: AppCliArgs) -> AppResult<Option<String>> {
     match args.command() {
         AppSubCommands::Create(command_args) => {
-            handle_subcommands::create(&args, &command_args)?;
+            handle_subcommands::create(&args, command_args)?;
             Ok(None)
         }
         AppSubCommands::Delete(delete_args) => {
@@ -40,10 +40,37 @@ fn subcommand(args: AppCliArgs) -> AppResult<Option<String>> {
         AppSubCommands::Pause(clock_ref) => {
             handle_subcommands::pause(&args, &clock_ref.kind_reference()?).map(|_| None)
         }
-        AppSubCommands::Reset(clock_ref) => {
-            handle_subcommands::reset(&args, &clock_ref.kind_reference()?).map(|_| None)
+        AppSubCommands::Reset(reset_args) => handle_subcommands::reset(
+            &args,
+            &reset_args.reference().kind_reference()?,
+            reset_args.archive(),
+        )
+        .map(|_| None),
+        AppSubCommands::Lap(clock_ref) => {
+            handle_subcommands::lap(&args, &clock_ref.kind_reference()?).map(|_| None)
+        }
+        AppSubCommands::Edit(edit_args) => handle_subcommands::edit(&args, edit_args).map(|_| None),
+        AppSubCommands::Adjust(adjust_args) => {
+            handle_subcommands::adjust(&args, adjust_args).map(|_| None)
+        }
+        AppSubCommands::Goal(goal_args) => handle_subcommands::goal(&args, goal_args).map(|_| None),
+        AppSubCommands::Snooze(snooze_args) => {
+            handle_subcommands::snooze(&args, snooze_args).map(|_| None)
+        }
+        AppSubCommands::Switch(reference) => {
+            handle_subcommands::switch(&args, reference).map(|_| None)
+        }
+        AppSubCommands::Exclusive(exclusive_args) => {
+            handle_subcommands::exclusive(&args, exclusive_args)
+        }
+        AppSubCommands::Cap(cap_args) => handle_subcommands::cap(&args, cap_args).map(|_| None),
+        AppSubCommands::Settings(settings_args) => {
+            handle_subcommands::settings(&args, settings_args)
+        }
+        AppSubCommands::Speed(speed_args) => {
+            handle_subcommands::speed(&args, speed_args).map(|_| None)
         }
         AppSubCommands::List(list_args) => handle_subcommands::list(&args, list_args).map(Some),
-        AppSubCommands::Get(get_args) => handle_subcommands::get_clock(&args, &get_args).map(Some),
+        AppSubCommands::Get(get_args) => handle_subcommands::get_clock(&args, get_args).map(Some),
     }
 }
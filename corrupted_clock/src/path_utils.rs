@@ -36,9 +36,9 @@ pub fn get_user_data_dir(args: &AppCliArgs) -> Result<PathBuf, NoDataDirErr> {
         }
         None => {
             let actual = if args.use_data_user_dir() {
-                data_store::path_to_app_dirs(|| dirs::data_dir(), || std::env::current_exe())
+                data_store::path_to_app_dirs(dirs::data_dir, std::env::current_exe)
             } else {
-                data_store::path_to_app_dirs(|| None, || std::env::current_exe())
+                data_store::path_to_app_dirs(|| None, std::env::current_exe)
             }?;
             Ok(actual)
         }
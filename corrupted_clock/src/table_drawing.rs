@@ -1,9 +1,61 @@
 use std::collections::VecDeque;
 
-use corrupted_clock_util::timing::{CountDown, Stopwatch, TimeImpl, Timer, UtcDateTime};
+use corrupted_clock_util::timing::{
+    Adjustment, Budget, BudgetPeriod, Clock, ClockDuration, ClockState, ClockType, CountDown, Lap,
+    PhaseRecord, Pomodoro, RateChange, Run, RunStats, Sequence, Settings, StageProgress, Stopwatch,
+    TimeChange, TimeImpl, TimeJump, TimeSegment, Timer, UtcDateTime,
+};
 use prettytable::{Cell, Row, Table};
 
-use crate::{constants::NOT_AVIABLE_TXT, listing_items_param::ListingItemsParams, AtLeastOne};
+use crate::{
+    constants::{self, NOT_AVIABLE_TXT},
+    listing_items_param::{ListingItemsParams, TableFormat},
+    AtLeastOne,
+};
+
+/// Rows of the clocks with the given kind, other kinds are skipped
+pub fn clock_rows<'a, T>(
+    list_args: ListingItemsParams,
+    clock_type: ClockType,
+    clocks: impl IntoIterator<Item = (&'a str, &'a Clock<T>)>,
+) -> String
+where
+    T: Default + TimeImpl + 'a,
+{
+    let clocks = clocks.into_iter();
+    match clock_type {
+        ClockType::Stopwatch => stop_watch_rows(
+            list_args,
+            clocks.filter_map(|(name, clock)| clock.as_stopwatch().map(|clock| (name, clock))),
+        ),
+        ClockType::CountDown => count_down_rows(
+            list_args,
+            clocks.filter_map(|(name, clock)| clock.as_count_down().map(|clock| (name, clock))),
+        ),
+        ClockType::Pomodoro => pomodoro_rows(
+            list_args,
+            clocks.filter_map(|(name, clock)| clock.as_pomodoro().map(|clock| (name, clock))),
+        ),
+        ClockType::Sequence => sequence_rows(
+            list_args,
+            clocks.filter_map(|(name, clock)| clock.as_sequence().map(|clock| (name, clock))),
+        ),
+        ClockType::Budget => budget_rows(
+            list_args,
+            clocks.filter_map(|(name, clock)| clock.as_budget().map(|clock| (name, clock))),
+        ),
+    }
+}
+
+pub fn clock_type_title(clock_type: ClockType) -> &'static str {
+    match clock_type {
+        ClockType::Stopwatch => "Stopwatches",
+        ClockType::CountDown => "Countdowns",
+        ClockType::Pomodoro => "Pomodoros",
+        ClockType::Sequence => "Sequences",
+        ClockType::Budget => "Budgets",
+    }
+}
 
 pub fn count_down_rows<'a, T>(
     list_args: ListingItemsParams,
@@ -12,13 +64,14 @@ pub fn count_down_rows<'a, T>(
 where
     T: Default + TimeImpl + 'a,
 {
+    let format = list_args.format();
     item_rows(
         list_args,
         count_downs,
-        || stopwatch_header().chain(count_down_headers()),
+        || stopwatch_header(format).chain(count_down_headers(format)),
         |name_stop_watch| {
-            stopwatch_fields((name_stop_watch.0, name_stop_watch.1.stopwatch()))
-                .chain(count_down_fields(name_stop_watch.1))
+            stopwatch_fields(name_stop_watch, name_stop_watch.1.stopwatch(), format)
+                .chain(count_down_fields(name_stop_watch.1, format))
         },
     )
 }
@@ -30,14 +83,207 @@ pub fn stop_watch_rows<'a, T>(
 where
     T: Default + TimeImpl + 'a,
 {
+    let format = list_args.format();
     item_rows(
         list_args,
         count_downs,
-        || stopwatch_header(),
-        |name_stop_watch| stopwatch_fields(name_stop_watch),
+        || {
+            stopwatch_header(format)
+                .chain(goal_headers())
+                .chain(cap_headers(format))
+        },
+        |name_stop_watch| {
+            stopwatch_fields(name_stop_watch, name_stop_watch.1, format)
+                .chain(goal_fields(name_stop_watch.1, format))
+                .chain(cap_fields(name_stop_watch.1, format))
+        },
+    )
+}
+
+pub fn pomodoro_rows<'a, T>(
+    list_args: ListingItemsParams,
+    pomodoros: impl IntoIterator<Item = (&'a str, &'a Pomodoro<T>)>,
+) -> String
+where
+    T: Default + TimeImpl + 'a,
+{
+    let format = list_args.format();
+    item_rows(
+        list_args,
+        pomodoros,
+        || stopwatch_header(format).chain(pomodoro_headers()),
+        |(name, pomodoro)| {
+            stopwatch_fields((name, pomodoro), pomodoro.stopwatch(), format)
+                .chain(pomodoro_fields(pomodoro, format))
+        },
+    )
+}
+
+pub fn sequence_rows<'a, T>(
+    list_args: ListingItemsParams,
+    sequences: impl IntoIterator<Item = (&'a str, &'a Sequence<T>)>,
+) -> String
+where
+    T: Default + TimeImpl + 'a,
+{
+    let format = list_args.format();
+    item_rows(
+        list_args,
+        sequences,
+        || stopwatch_header(format).chain(sequence_headers()),
+        |(name, sequence)| {
+            stopwatch_fields((name, sequence), sequence.stopwatch(), format)
+                .chain(sequence_fields(sequence, format))
+        },
+    )
+}
+
+pub fn budget_rows<'a, T>(
+    list_args: ListingItemsParams,
+    budgets: impl IntoIterator<Item = (&'a str, &'a Budget<T>)>,
+) -> String
+where
+    T: Default + TimeImpl + 'a,
+{
+    let format = list_args.format();
+    item_rows(
+        list_args,
+        budgets,
+        || stopwatch_header(format).chain(budget_headers(format)),
+        |(name, budget)| {
+            stopwatch_fields((name, budget), budget.stopwatch(), format)
+                .chain(budget_fields(budget, format))
+        },
+    )
+}
+
+/// Table with a header row and one row per item.
+/// The fields of an item are given its index to number the rows.
+fn numbered_rows<'a, T, I>(
+    headers: impl Iterator<Item = Cell>,
+    items: &'a [T],
+    mut on_fields: impl FnMut(usize, &'a T) -> I,
+) -> String
+where
+    I: Iterator<Item = String>,
+{
+    let mut table = Table::new();
+    table.add_row(Row::from_iter(headers));
+    for (index, item) in items.iter().enumerate() {
+        table.add_row(Row::from_iter(
+            on_fields(index, item).map(|field| Cell::new(&field)),
+        ));
+    }
+    table.to_string()
+}
+
+pub fn lap_rows(laps: &[Lap], format: TableFormat) -> String {
+    numbered_rows(lap_headers(format), laps, |index, lap| {
+        lap_fields(index, lap, format)
+    })
+}
+
+pub fn segment_rows(segments: &[TimeSegment], now: UtcDateTime, format: TableFormat) -> String {
+    numbered_rows(segment_headers(format), segments, |index, segment| {
+        segment_fields(index, segment, now, format)
+    })
+}
+
+pub fn adjustment_rows(adjustments: &[Adjustment], format: TableFormat) -> String {
+    numbered_rows(
+        adjustment_headers(format),
+        adjustments,
+        |index, adjustment| adjustment_fields(index, adjustment, format),
+    )
+}
+
+pub fn time_change_rows(time_changes: &[TimeChange], format: TableFormat) -> String {
+    numbered_rows(
+        time_change_headers(format),
+        time_changes,
+        |index, time_change| time_change_fields(index, time_change, format),
+    )
+}
+
+pub fn phase_rows(phases: &[PhaseRecord], format: TableFormat) -> String {
+    numbered_rows(phase_headers(format), phases, |index, phase| {
+        phase_fields(index, phase, format)
+    })
+}
+
+pub fn stage_rows(stages: &[StageProgress], format: TableFormat) -> String {
+    numbered_rows(stage_headers(format), stages, |index, stage| {
+        stage_fields(index, stage, format)
+    })
+}
+
+pub fn period_rows(periods: &[BudgetPeriod], format: TableFormat) -> String {
+    numbered_rows(period_headers(format), periods, |index, period| {
+        period_fields(index, period, format)
+    })
+}
+
+pub fn run_rows(runs: &[Run], format: TableFormat) -> String {
+    numbered_rows(run_headers(format), runs, |index, run| {
+        run_fields(index, run, format)
+    })
+}
+
+/// Single row with the statistics over the runs. Not available fields if there are no runs.
+pub fn run_stats_rows(runs: &[Run], format: TableFormat) -> String {
+    let stats = RunStats::new(runs);
+    let or_not_available = |field: Option<ClockDuration>| {
+        field
+            .map(|duration| duration.display(format).to_string())
+            .unwrap_or(NOT_AVIABLE_TXT.to_string())
+    };
+    let mut table = Table::new();
+    table.add_row(Row::from_iter(
+        ["Count", "Average", "Best", "Worst"].map(Cell::new),
+    ));
+    table.add_row(Row::from_iter(
+        [
+            runs.len().to_string(),
+            or_not_available(stats.map(|stats| stats.average())),
+            or_not_available(stats.map(|stats| stats.best())),
+            or_not_available(stats.map(|stats| stats.worst())),
+        ]
+        .map(|field| Cell::new(&field)),
+    ));
+    table.to_string()
+}
+
+pub fn rate_change_rows(rate_changes: &[RateChange], format: TableFormat) -> String {
+    numbered_rows(
+        rate_change_headers(format),
+        rate_changes,
+        |index, rate_change| rate_change_fields(index, rate_change, format),
     )
 }
 
+pub fn time_jump_rows(time_jumps: &[TimeJump], format: TableFormat) -> String {
+    numbered_rows(time_jump_headers(format), time_jumps, |index, time_jump| {
+        time_jump_fields(index, time_jump, format)
+    })
+}
+
+pub fn settings_rows(settings: &Settings) -> String {
+    let mut table = Table::new();
+    table.add_row(Row::from_iter(["Setting", "Value"].map(Cell::new)));
+    let default_cap = settings
+        .default_cap()
+        .map(|cap| cap.to_string())
+        .unwrap_or(NOT_AVIABLE_TXT.to_string());
+    for (setting, value) in [
+        ("Exclusive mode", settings.exclusive_mode().to_string()),
+        ("Default cap", default_cap),
+        ("Time jump policy", settings.time_jump_policy().to_string()),
+    ] {
+        table.add_row(Row::from_iter([Cell::new(setting), Cell::new(&value)]));
+    }
+    table.to_string()
+}
+
 fn item_rows<'a, T: 'a, F, H>(
     list_args: ListingItemsParams,
     count_downs: impl IntoIterator<Item = (&'a str, &'a T)>,
@@ -53,9 +299,7 @@ where
     let mut headers: VecDeque<Cell> = on_headers().collect();
     let elements_num = headers.len();
     let colum_steps = column_draw_steps(elements_num as u32, list_args.column_num());
-    let mut tables: Vec<Table> = std::iter::repeat(Table::new())
-        .take(colum_steps.len())
-        .collect();
+    let mut tables: Vec<Table> = std::iter::repeat_n(Table::new(), colum_steps.len()).collect();
 
     for (next, &split_off_at) in tables.iter_mut().zip(colum_steps.iter()) {
         let split_off_at = split_off_at as usize;
@@ -78,64 +322,523 @@ where
         .join("\n")
 }
 
-fn stopwatch_header() -> impl Iterator<Item = Cell> {
+fn stopwatch_header(format: TableFormat) -> impl Iterator<Item = Cell> {
     [
-        "Name",
-        "Created at",
-        "Started at",
-        "Is paused",
-        "Passed Time",
-        "Paused Time",
-        "Last resumed at",
-        "Last paused at",
+        "Name".to_string(),
+        "Group".to_string(),
+        "Time zone".to_string(),
+        format.date_header("Created at"),
+        format.date_header("Started at"),
+        "State".to_string(),
+        "Speed".to_string(),
+        "Passed Time".to_string(),
+        "Paused Time".to_string(),
+        format.date_header("Last resumed at"),
+        format.date_header("Last paused at"),
     ]
-    .map(Cell::new)
+    .map(|header| Cell::new(&header))
     .into_iter()
 }
 
-fn stopwatch_fields<'a, T>((name, stop_watch): (&'a str, &'a T)) -> impl Iterator<Item = String>
+fn stopwatch_fields<'a, T, S>(
+    (name, stop_watch): (&'a str, &'a T),
+    settings: &Stopwatch<S>,
+    format: TableFormat,
+) -> impl Iterator<Item = String>
 where
     T: Timer + 'a,
+    S: Default,
 {
-    fn to_local_short_table_field(date: UtcDateTime) -> String {
-        corrupted_clock_util::chrono_time_to_str(corrupted_clock_util::convert_utc_to_local(date))
-    }
-
-    fn convert_to_opt_table_field(opt: Option<UtcDateTime>) -> String {
-        if let Some(date) = opt {
-            to_local_short_table_field(date)
-        } else {
-            NOT_AVIABLE_TXT.to_string()
-        }
-    }
-
-    let local_create_at = to_local_short_table_field(stop_watch.created_at());
-    let local_started_at = to_local_short_table_field(stop_watch.start_moment());
-    let last_resumed = convert_to_opt_table_field(stop_watch.last_resumed_at());
-    let last_paused = convert_to_opt_table_field(stop_watch.last_paused_at());
+    let local_create_at = format.date_to_str(stop_watch.created_at());
+    let local_started_at = format.date_to_str(stop_watch.start_moment());
+    let last_resumed = convert_to_opt_table_field(stop_watch.last_resumed_at(), format);
+    let last_paused = convert_to_opt_table_field(stop_watch.last_paused_at(), format);
 
     [
         name.to_string(),
+        settings.group().unwrap_or(NOT_AVIABLE_TXT).to_string(),
+        settings
+            .time_zone()
+            .map(|zone| zone.name())
+            .unwrap_or(NOT_AVIABLE_TXT)
+            .to_string(),
         local_create_at,
         local_started_at,
-        stop_watch.is_paused().to_string(),
-        stop_watch.passed().to_string(),
-        stop_watch.paused_time().to_string(),
+        state_field(stop_watch, format),
+        settings.speed().to_string(),
+        stop_watch.passed().display(format).to_string(),
+        stop_watch.paused_time().display(format).to_string(),
         last_resumed,
         last_paused,
     ]
     .into_iter()
 }
 
-fn count_down_headers() -> impl Iterator<Item = Cell> {
-    ["Count down", "Left Time"].map(Cell::new).into_iter()
+fn state_field(clock: &impl Timer, format: TableFormat) -> String {
+    match (clock.state(), clock.starts_in()) {
+        (ClockState::Scheduled, Some(starts_in)) => {
+            format!("scheduled, starts in {}", starts_in.display(format))
+        }
+        (state, _) => state.to_string(),
+    }
+}
+
+fn goal_headers() -> impl Iterator<Item = Cell> {
+    ["Goal", "Goal reached", "Left to goal", "Over goal"]
+        .map(Cell::new)
+        .into_iter()
+}
+
+fn goal_fields<T>(stop_watch: &Stopwatch<T>, format: TableFormat) -> impl Iterator<Item = String>
+where
+    T: Default + TimeImpl,
+{
+    let or_not_available = |field: Option<String>| field.unwrap_or(NOT_AVIABLE_TXT.to_string());
+    [
+        or_not_available(
+            stop_watch
+                .goal()
+                .map(|goal| goal.display(format).to_string()),
+        ),
+        or_not_available(
+            stop_watch
+                .goal_percent()
+                .map(|percent| format!("{}%", percent)),
+        ),
+        or_not_available(
+            stop_watch
+                .left_to_goal()
+                .map(|left| left.display(format).to_string()),
+        ),
+        or_not_available(
+            stop_watch
+                .over_goal()
+                .map(|over| over.display(format).to_string()),
+        ),
+    ]
+    .into_iter()
+}
+
+fn cap_headers(format: TableFormat) -> impl Iterator<Item = Cell> {
+    ["Cap".to_string(), format.date_header("Auto-stopped at")]
+        .map(|header| Cell::new(&header))
+        .into_iter()
+}
+
+fn cap_fields<T>(stop_watch: &Stopwatch<T>, format: TableFormat) -> impl Iterator<Item = String>
+where
+    T: Default + TimeImpl,
+{
+    [
+        stop_watch
+            .cap()
+            .map(|cap| cap.display(format).to_string())
+            .unwrap_or(NOT_AVIABLE_TXT.to_string()),
+        convert_to_opt_table_field(stop_watch.auto_stopped_at(), format),
+    ]
+    .into_iter()
+}
+
+fn lap_headers(format: TableFormat) -> impl Iterator<Item = Cell> {
+    [
+        "Lap".to_string(),
+        format.date_header("Recorded at"),
+        "Split Time".to_string(),
+        "Total Time".to_string(),
+    ]
+    .map(|header| Cell::new(&header))
+    .into_iter()
+}
+
+fn lap_fields(index: usize, lap: &Lap, format: TableFormat) -> impl Iterator<Item = String> {
+    [
+        (index + 1).to_string(),
+        format.date_to_str(lap.recorded_at()),
+        lap.split().display(format).to_string(),
+        lap.total().display(format).to_string(),
+    ]
+    .into_iter()
+}
+
+fn segment_headers(format: TableFormat) -> impl Iterator<Item = Cell> {
+    [
+        "Segment".to_string(),
+        format.date_header("Started at"),
+        format.date_header("Ended at"),
+        "Duration".to_string(),
+    ]
+    .map(|header| Cell::new(&header))
+    .into_iter()
+}
+
+fn segment_fields(
+    index: usize,
+    segment: &TimeSegment,
+    now: UtcDateTime,
+    format: TableFormat,
+) -> impl Iterator<Item = String> {
+    [
+        (index + 1).to_string(),
+        format.date_to_str(segment.start()),
+        convert_to_opt_table_field(segment.end(), format),
+        segment.duration(now).display(format).to_string(),
+    ]
+    .into_iter()
+}
+
+fn adjustment_headers(format: TableFormat) -> impl Iterator<Item = Cell> {
+    [
+        "Adjustment".to_string(),
+        format.date_header("Recorded at"),
+        "Amount".to_string(),
+        "Reason".to_string(),
+    ]
+    .map(|header| Cell::new(&header))
+    .into_iter()
+}
+
+fn adjustment_fields(
+    index: usize,
+    adjustment: &Adjustment,
+    format: TableFormat,
+) -> impl Iterator<Item = String> {
+    [
+        (index + 1).to_string(),
+        format.date_to_str(adjustment.recorded_at()),
+        with_sign(adjustment.amount(), format),
+        adjustment.reason().unwrap_or(NOT_AVIABLE_TXT).to_string(),
+    ]
+    .into_iter()
+}
+
+fn time_change_headers(format: TableFormat) -> impl Iterator<Item = Cell> {
+    [
+        "Change".to_string(),
+        format.date_header("Recorded at"),
+        "From".to_string(),
+        "To".to_string(),
+        "Difference".to_string(),
+    ]
+    .map(|header| Cell::new(&header))
+    .into_iter()
+}
+
+fn time_change_fields(
+    index: usize,
+    time_change: &TimeChange,
+    format: TableFormat,
+) -> impl Iterator<Item = String> {
+    [
+        (index + 1).to_string(),
+        format.date_to_str(time_change.recorded_at()),
+        time_change.previous().display(format).to_string(),
+        time_change.new_time().display(format).to_string(),
+        with_sign(time_change.difference(), format),
+    ]
+    .into_iter()
+}
+
+fn run_headers(format: TableFormat) -> impl Iterator<Item = Cell> {
+    [
+        "Run".to_string(),
+        format.date_header("Started at"),
+        format.date_header("Ended at"),
+        "Passed Time".to_string(),
+        "Paused Time".to_string(),
+    ]
+    .map(|header| Cell::new(&header))
+    .into_iter()
+}
+
+fn run_fields(index: usize, run: &Run, format: TableFormat) -> impl Iterator<Item = String> {
+    [
+        (index + 1).to_string(),
+        format.date_to_str(run.started_at()),
+        format.date_to_str(run.ended_at()),
+        run.passed().display(format).to_string(),
+        run.paused().display(format).to_string(),
+    ]
+    .into_iter()
+}
+
+fn rate_change_headers(format: TableFormat) -> impl Iterator<Item = Cell> {
+    [
+        "Change".to_string(),
+        format.date_header("Changed at"),
+        "Speed".to_string(),
+    ]
+    .map(|header| Cell::new(&header))
+    .into_iter()
+}
+
+fn rate_change_fields(
+    index: usize,
+    rate_change: &RateChange,
+    format: TableFormat,
+) -> impl Iterator<Item = String> {
+    [
+        (index + 1).to_string(),
+        format.date_to_str(rate_change.changed_at()),
+        rate_change.speed().to_string(),
+    ]
+    .into_iter()
+}
+
+fn time_jump_headers(format: TableFormat) -> impl Iterator<Item = Cell> {
+    [
+        "Jump".to_string(),
+        format.date_header("Detected at"),
+        "Backwards by".to_string(),
+    ]
+    .map(|header| Cell::new(&header))
+    .into_iter()
+}
+
+fn time_jump_fields(
+    index: usize,
+    time_jump: &TimeJump,
+    format: TableFormat,
+) -> impl Iterator<Item = String> {
+    [
+        (index + 1).to_string(),
+        format.date_to_str(time_jump.detected_at()),
+        time_jump.backwards_by().display(format).to_string(),
+    ]
+    .into_iter()
+}
+
+fn phase_headers(format: TableFormat) -> impl Iterator<Item = Cell> {
+    [
+        "Phase".to_string(),
+        "Kind".to_string(),
+        format.date_header("Started at"),
+        format.date_header("Ended at"),
+    ]
+    .map(|header| Cell::new(&header))
+    .into_iter()
+}
+
+fn phase_fields(
+    index: usize,
+    phase: &PhaseRecord,
+    format: TableFormat,
+) -> impl Iterator<Item = String> {
+    [
+        (index + 1).to_string(),
+        phase.phase().to_string(),
+        format.date_to_str(phase.started_at()),
+        format.date_to_str(phase.ended_at()),
+    ]
+    .into_iter()
+}
+
+fn stage_headers(format: TableFormat) -> impl Iterator<Item = Cell> {
+    [
+        "Stage".to_string(),
+        "Label".to_string(),
+        "Duration".to_string(),
+        "Status".to_string(),
+        "Left".to_string(),
+        format.date_header("Started at"),
+        format.date_header("Ended at"),
+    ]
+    .map(|header| Cell::new(&header))
+    .into_iter()
+}
+
+fn stage_fields(
+    index: usize,
+    progress: &StageProgress,
+    format: TableFormat,
+) -> impl Iterator<Item = String> {
+    [
+        (index + 1).to_string(),
+        progress.stage().label().to_string(),
+        progress.stage().duration().display(format).to_string(),
+        progress.status().to_string(),
+        progress.left().display(format).to_string(),
+        convert_to_opt_table_field(progress.started_at(), format),
+        convert_to_opt_table_field(progress.ended_at(), format),
+    ]
+    .into_iter()
+}
+
+fn period_headers(format: TableFormat) -> impl Iterator<Item = Cell> {
+    [
+        "Period".to_string(),
+        format.date_header("Started at"),
+        format.date_header("Refilled at"),
+        "Carried".to_string(),
+        "Available".to_string(),
+        "Used".to_string(),
+        "Left".to_string(),
+    ]
+    .map(|header| Cell::new(&header))
+    .into_iter()
+}
+
+fn period_fields(
+    index: usize,
+    period: &BudgetPeriod,
+    format: TableFormat,
+) -> impl Iterator<Item = String> {
+    [
+        (index + 1).to_string(),
+        format.date_to_str(period.start()),
+        format.date_to_str(period.end()),
+        period.carried().display(format).to_string(),
+        period.available().display(format).to_string(),
+        period.used().display(format).to_string(),
+        period.left().display(format).to_string(),
+    ]
+    .into_iter()
+}
+
+/// Prefixes a non negative duration with "+"
+fn with_sign(duration: ClockDuration, format: TableFormat) -> String {
+    let sign = if duration.is_negative() { "" } else { "+" };
+    format!("{}{}", sign, duration.display(format))
+}
+
+fn convert_to_opt_table_field(opt: Option<UtcDateTime>, format: TableFormat) -> String {
+    if let Some(date) = opt {
+        format.date_to_str(date)
+    } else {
+        NOT_AVIABLE_TXT.to_string()
+    }
+}
+
+fn count_down_headers(format: TableFormat) -> impl Iterator<Item = Cell> {
+    [
+        "Count down".to_string(),
+        "Left Time".to_string(),
+        format.date_header("Target"),
+        format.date_header("Finished at"),
+        "Overrun".to_string(),
+        "Cycle".to_string(),
+        "Cycles left".to_string(),
+    ]
+    .map(|header| Cell::new(&header))
+    .into_iter()
+}
+
+fn count_down_fields<T>(cd: &CountDown<T>, format: TableFormat) -> impl Iterator<Item = String>
+where
+    T: Default + TimeImpl,
+{
+    let overrun = if cd.allows_overrun() {
+        cd.overrun_time().display(format).to_string()
+    } else {
+        NOT_AVIABLE_TXT.to_string()
+    };
+    let cycle = match cd.repeat_policy().cycles() {
+        Some(cycles) => format!("{}/{}", cd.current_cycle() + 1, cycles),
+        None => (cd.current_cycle() + 1).to_string(),
+    };
+    let cycles_left = cd
+        .remaining_cycles()
+        .map(|left| left.to_string())
+        .unwrap_or_else(|| constants::REPEAT_FOREVER.to_string());
+    [
+        cd.count_down_time().display(format).to_string(),
+        cd.left_time().display(format).to_string(),
+        convert_to_opt_table_field(cd.target(), format),
+        convert_to_opt_table_field(cd.finished_at(), format),
+        overrun,
+        cycle,
+        cycles_left,
+    ]
+    .into_iter()
+}
+
+fn pomodoro_headers() -> impl Iterator<Item = Cell> {
+    [
+        "Phase",
+        "Left in phase",
+        "Completed pomodoros",
+        "Work",
+        "Short break",
+        "Long break",
+        "Long break every",
+    ]
+    .map(Cell::new)
+    .into_iter()
+}
+
+fn pomodoro_fields<T>(pomodoro: &Pomodoro<T>, format: TableFormat) -> impl Iterator<Item = String>
+where
+    T: Default + TimeImpl,
+{
+    let config = pomodoro.config();
+    [
+        pomodoro.current_phase().to_string(),
+        pomodoro.left_in_phase().display(format).to_string(),
+        pomodoro.completed_pomodoros().to_string(),
+        config.work().display(format).to_string(),
+        config.short_break().display(format).to_string(),
+        config.long_break().display(format).to_string(),
+        config.long_break_interval().to_string(),
+    ]
+    .into_iter()
+}
+
+fn sequence_headers() -> impl Iterator<Item = Cell> {
+    ["Stage", "Left in stage", "Total", "Total left"]
+        .map(Cell::new)
+        .into_iter()
+}
+
+fn sequence_fields<T>(sequence: &Sequence<T>, format: TableFormat) -> impl Iterator<Item = String>
+where
+    T: Default + TimeImpl,
+{
+    let stage = match sequence.current_stage() {
+        Some((index, stage)) => format!(
+            "{}/{} {}",
+            index + 1,
+            sequence.stages().len(),
+            stage.label()
+        ),
+        None => NOT_AVIABLE_TXT.to_string(),
+    };
+    [
+        stage,
+        sequence.left_in_stage().display(format).to_string(),
+        sequence.total_time().display(format).to_string(),
+        sequence.total_left().display(format).to_string(),
+    ]
+    .into_iter()
+}
+
+fn budget_headers(format: TableFormat) -> impl Iterator<Item = Cell> {
+    [
+        "Allowance".to_string(),
+        "Refill".to_string(),
+        "Carry-over".to_string(),
+        "Used in period".to_string(),
+        "Left in period".to_string(),
+        format.date_header("Refills at"),
+    ]
+    .map(|header| Cell::new(&header))
+    .into_iter()
 }
 
-fn count_down_fields<'a, T>(cd: &CountDown<T>) -> impl Iterator<Item = String>
+fn budget_fields<T>(budget: &Budget<T>, format: TableFormat) -> impl Iterator<Item = String>
 where
     T: Default + TimeImpl,
 {
-    [cd.count_down_time().to_string(), cd.left_time().to_string()].into_iter()
+    let period = budget.current_period();
+    [
+        budget.allowance().display(format).to_string(),
+        budget.refill().to_string(),
+        budget.carry_over().to_string(),
+        period
+            .map(|period| period.used().display(format).to_string())
+            .unwrap_or(NOT_AVIABLE_TXT.to_string()),
+        budget.left_in_period().display(format).to_string(),
+        convert_to_opt_table_field(period.map(|period| period.end()), format),
+    ]
+    .into_iter()
 }
 
 fn column_draw_steps(colum_num: u32, opt_column_num_per_row: Option<AtLeastOne>) -> Vec<u32> {
@@ -148,7 +851,7 @@ fn column_draw_steps(colum_num: u32, opt_column_num_per_row: Option<AtLeastOne>)
             } else {
                 let module_column: u32 = colum_num % num_per_row;
                 let div_column: u32 = colum_num / num_per_row;
-                let rows = std::iter::repeat(num_per_row).take(div_column as usize);
+                let rows = std::iter::repeat_n(num_per_row, div_column as usize);
                 if module_column == 0 {
                     rows.collect()
                 } else {
@@ -161,8 +864,194 @@ fn column_draw_steps(colum_num: u32, opt_column_num_per_row: Option<AtLeastOne>)
 
 #[cfg(test)]
 mod testing {
+    use chrono::{TimeDelta, TimeZone, Utc};
+    use corrupted_clock_util::timing::{
+        mocking_time::MockTimeImpl, CarryOver, ClockDuration, DurationFormat, PomodoroPhase,
+        RefillPeriod, Stage, Tz,
+    };
+
+    use crate::display_zone::DisplayZone;
+
     use super::*;
 
+    /// Dates are shown in UTC so the snapshots do not depend on the local zone
+    fn utc_format() -> TableFormat {
+        TableFormat::new(DurationFormat::default(), DisplayZone::Utc)
+    }
+
+    /// Moment at which the first item of a drawn table was recorded
+    fn recorded_at() -> UtcDateTime {
+        Utc.with_ymd_and_hms(2024, 5, 1, 8, 20, 40).unwrap()
+    }
+
+    fn minutes(minutes: i64) -> ClockDuration {
+        ClockDuration::new_secs_mins_hours(None, Some(minutes), None)
+    }
+
+    #[test]
+    fn draw_lap_table() {
+        let at = recorded_at();
+        let laps = [
+            Lap::new(at, minutes(10), minutes(10)),
+            Lap::new(
+                at + TimeDelta::minutes(12),
+                ClockDuration::new_secs_mins_hours(None, Some(2), Some(30)),
+                ClockDuration::new_secs_mins_hours(None, Some(12), Some(30)),
+            ),
+        ];
+        let actual = lap_rows(&laps, utc_format());
+        insta::assert_snapshot!(actual);
+    }
+
+    #[test]
+    fn draw_segment_table() {
+        let at = recorded_at();
+        let segments = [
+            TimeSegment::new(at, at + TimeDelta::minutes(30)),
+            TimeSegment::new_open(at + TimeDelta::hours(1)),
+        ];
+        let actual = segment_rows(&segments, at + TimeDelta::hours(2), utc_format());
+        insta::assert_snapshot!(actual);
+    }
+
+    #[test]
+    fn draw_segment_table_in_other_zones() {
+        let at = recorded_at();
+        let segments = [TimeSegment::new(at, at + TimeDelta::minutes(30))];
+        let actual = [DisplayZone::Utc, DisplayZone::Named(Tz::America__New_York)]
+            .map(|zone| {
+                segment_rows(
+                    &segments,
+                    at + TimeDelta::hours(2),
+                    TableFormat::new(DurationFormat::default(), zone),
+                )
+            })
+            .join("\n");
+        insta::assert_snapshot!(actual);
+    }
+
+    #[test]
+    fn draw_adjustment_table() {
+        let at = recorded_at();
+        let adjustments = [
+            Adjustment::new(
+                at,
+                minutes(15),
+                Some("Started before the command".to_string()),
+            ),
+            Adjustment::new(at + TimeDelta::minutes(12), -minutes(5), None),
+        ];
+        let actual = adjustment_rows(&adjustments, utc_format());
+        insta::assert_snapshot!(actual);
+    }
+
+    #[test]
+    fn draw_time_change_table() {
+        let at = recorded_at();
+        let ten_minutes = minutes(10);
+        let fifteen_minutes = minutes(15);
+        let time_changes = [
+            TimeChange::new(at, ten_minutes, fifteen_minutes),
+            TimeChange::new(at + TimeDelta::minutes(12), fifteen_minutes, ten_minutes),
+        ];
+        let actual = time_change_rows(&time_changes, utc_format());
+        insta::assert_snapshot!(actual);
+    }
+
+    #[test]
+    fn draw_rate_change_table() {
+        let at = recorded_at();
+        let rate_changes = [
+            RateChange::new(at, "10".parse().unwrap()),
+            RateChange::new(at + TimeDelta::minutes(12), "1/3".parse().unwrap()),
+        ];
+        let actual = rate_change_rows(&rate_changes, utc_format());
+        insta::assert_snapshot!(actual);
+    }
+
+    #[test]
+    fn draw_run_tables() {
+        let at = recorded_at();
+        let runs = [
+            Run::new(at, at + TimeDelta::minutes(40), minutes(30), minutes(10)),
+            Run::new(
+                at + TimeDelta::hours(2),
+                at + TimeDelta::hours(3),
+                ClockDuration::new_secs_mins_hours(Some(1), None, None),
+                ClockDuration::default(),
+            ),
+        ];
+        let actual = [
+            run_rows(&runs, utc_format()),
+            run_stats_rows(&runs, utc_format()),
+            run_stats_rows(&[], utc_format()),
+        ]
+        .join("\n");
+        insta::assert_snapshot!(actual);
+    }
+
+    #[test]
+    fn draw_time_jump_table() {
+        let at = recorded_at();
+        let time_jumps = [
+            TimeJump::new(at, minutes(42)),
+            TimeJump::new(
+                at + TimeDelta::hours(3),
+                ClockDuration::new_secs_mins_hours(Some(1), None, Some(5)),
+            ),
+        ];
+        let actual = time_jump_rows(&time_jumps, utc_format());
+        insta::assert_snapshot!(actual);
+    }
+
+    #[test]
+    fn draw_phase_table() {
+        let at = recorded_at();
+        let phases = [
+            PhaseRecord::new(PomodoroPhase::Work, at, at + TimeDelta::minutes(25)),
+            PhaseRecord::new(
+                PomodoroPhase::ShortBreak,
+                at + TimeDelta::minutes(25),
+                at + TimeDelta::minutes(30),
+            ),
+        ];
+        let actual = phase_rows(&phases, utc_format());
+        insta::assert_snapshot!(actual);
+    }
+
+    #[test]
+    fn draw_stage_table() {
+        let time = MockTimeImpl::new(recorded_at());
+        let stages = [("prep", 2), ("run", 20), ("cooldown", 5)]
+            .map(|(label, length)| Stage::new(label.to_string(), minutes(length)))
+            .into();
+        let sequence = Sequence::new_with_impl(time.clone(), stages);
+        time.add_to_now(TimeDelta::minutes(7));
+        let actual = stage_rows(&sequence.stage_progress(), utc_format());
+        insta::assert_snapshot!(actual);
+    }
+
+    #[test]
+    fn draw_period_table() {
+        let time = MockTimeImpl::new(recorded_at());
+        let mut clock: Clock<MockTimeImpl> = Budget::new_with_impl(
+            time.clone(),
+            ClockDuration::new_secs_mins_hours(Some(1), Some(30), None),
+            RefillPeriod::Daily,
+            CarryOver::All,
+        )
+        .into();
+        clock
+            .mut_stopwatch()
+            .set_time_zone(Some(Tz::Europe__Berlin));
+        time.add_to_now(TimeDelta::hours(2));
+        clock.pause();
+        time.add_to_now(TimeDelta::days(1));
+        let periods = clock.as_budget().unwrap().periods();
+        let actual = period_rows(&periods, utc_format());
+        insta::assert_snapshot!(actual);
+    }
+
     #[test]
     fn colum_draw_steps_more_per_row_than_columns() {
         let actual = column_draw_steps(2, Some(AtLeastOne::new(4).unwrap()));
@@ -112,8 +112,7 @@ mod testing {
             "no_executable_or_data_dir",
             || None,
             || {
-                Err(io::Error::new(
-                    io::ErrorKind::Other,
+                Err(io::Error::other(
                     "No location found for saving the app state",
                 ))
             },
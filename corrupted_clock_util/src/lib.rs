@@ -4,6 +4,8 @@ pub mod constants;
 pub mod data_store;
 pub mod parsed_date;
 pub mod prelude;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod timing;
 
 pub fn convert_utc_to_local(date_time: DateTime<Utc>) -> DateTime<Local> {
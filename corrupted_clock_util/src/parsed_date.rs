@@ -1,23 +1,56 @@
 use std::str::FromStr;
 
-use chrono::{offset::LocalResult, TimeZone, Utc};
+use chrono::{offset::LocalResult, Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeZone};
+use chrono_tz::Tz;
 use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
 
 use crate::timing::UtcDateTime;
 
+const TODAY: &str = "today";
+const TOMORROW: &str = "tomorrow";
+
 static REGEX_DATE: Lazy<Regex> = Lazy::new(|| {
-    const DATE: &str = r"(?<year>\d+)-(?<month>[0-1]?\d)-(?<day>[0-3]?\d)";
-    const TIME: &str = r"(?<hours>\d{1,2}):(?<minutes>\d{1,2}):(?<seconds>\d{1,2})";
+    const DATE: &str =
+        r"(?:(?<year>\d+)-(?<month>[0-1]?\d)-(?<day>[0-3]?\d)|(?<relative>(?i:today|tomorrow)))";
+    const TIME: &str = r"(?<hours>\d{1,2}):(?<minutes>\d{1,2})(?::(?<seconds>\d{1,2}))?";
+    const ZONE: &str =
+        r"(?:\s*(?<offset>Z|[+-]\d{2}:?\d{2})|\s+(?<zone>UTC|[A-Za-z_]+(?:/[A-Za-z0-9_+-]+)+))?$";
 
-    Regex::new(&format!("{}{}{}", DATE, r"[\s,_]+", TIME)).unwrap()
+    Regex::new(&format!("{}{}{}{}", DATE, r"[\s,_]+", TIME, ZONE)).unwrap()
 });
+/// Date and time with an optional time zone or UTC offset at the end,
+/// like "2024-05-01 08:00 Europe/Berlin" or "2024-05-01 08:00+02:00".
+/// The seconds are optional.
+/// Instead of a date, "today" or "tomorrow" can be given.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParsedDate {
+    date: NaiveDateTime,
+    zone: Option<DateZone>,
+}
+
+/// Explicit zone given after a date
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub struct ParsedDate(UtcDateTime);
+pub enum DateZone {
+    /// IANA time zone, like "Europe/Berlin"
+    Named(Tz),
+    /// Fixed offset to UTC, like "+02:00" or "Z"
+    Offset(FixedOffset),
+}
+
+impl From<NaiveDateTime> for ParsedDate {
+    fn from(date: NaiveDateTime) -> Self {
+        Self { date, zone: None }
+    }
+}
 
-impl From<ParsedDate> for UtcDateTime {
-    fn from(value: ParsedDate) -> Self {
-        value.0
+/// A date without zone is taken as UTC.
+/// Like [`ParsedDate::to_utc_in`], it reports a date which is ambiguous or does not exist in its zone.
+impl TryFrom<ParsedDate> for UtcDateTime {
+    type Error = InvalidDateFormat;
+
+    fn try_from(value: ParsedDate) -> Result<Self, Self::Error> {
+        value.to_utc_in(&chrono::Utc)
     }
 }
 
@@ -42,12 +75,56 @@ pub enum InvalidDateFormat {
     NoValidDate,
     #[error("There is more date than one date for the given input")]
     Ambiguous,
+    #[error("There is no time zone with the given name")]
+    NoTimeZone,
+    #[error("There is no UTC offset between -23:59 and +23:59")]
+    NoOffset,
 }
 
 impl FromStr for ParsedDate {
     type Err = InvalidDateFormat;
 
+    /// "today" and "tomorrow" are relative to the local date of the user.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_today(s, crate::local_now().date_naive())
+    }
+}
+
+impl ParsedDate {
+    /// Date and time as written in the input
+    pub fn date(&self) -> NaiveDateTime {
+        self.date
+    }
+
+    /// Zone which was given explicitly in the input
+    pub fn zone(&self) -> Option<DateZone> {
+        self.zone
+    }
+
+    /// Moment of this date in the given zone if no zone was given explicitly.
+    pub fn to_utc_in<Z>(&self, default_zone: &Z) -> Result<UtcDateTime, InvalidDateFormat>
+    where
+        Z: TimeZone,
+    {
+        fn to_utc<Z: TimeZone>(
+            local: LocalResult<chrono::DateTime<Z>>,
+        ) -> Result<UtcDateTime, InvalidDateFormat> {
+            match local {
+                LocalResult::Single(date) => Ok(date.to_utc()),
+                LocalResult::Ambiguous(_, _) => Err(InvalidDateFormat::Ambiguous),
+                LocalResult::None => Err(InvalidDateFormat::NoValidDate),
+            }
+        }
+
+        match self.zone {
+            Some(DateZone::Named(zone)) => to_utc(zone.from_local_datetime(&self.date)),
+            Some(DateZone::Offset(offset)) => to_utc(offset.from_local_datetime(&self.date)),
+            None => to_utc(default_zone.from_local_datetime(&self.date)),
+        }
+    }
+
+    /// Parses the input with "today" and "tomorrow" being relative to the given `today`.
+    pub fn parse_with_today(s: &str, today: NaiveDate) -> Result<Self, InvalidDateFormat> {
         fn parse_comp<T>(
             cap: &Captures,
             key: &str,
@@ -56,25 +133,38 @@ impl FromStr for ParsedDate {
         where
             T: FromStr,
         {
-            cap.name(key)
-                .ok_or(err.clone())?
-                .as_str()
-                .parse()
-                .map_err(|_| err)
+            cap.name(key).ok_or(err)?.as_str().parse().map_err(|_| err)
         }
 
         let matched = REGEX_DATE
             .captures(s)
-            .ok_or_else(|| InvalidDateFormat::InvalidDateFromat)?;
+            .ok_or(InvalidDateFormat::InvalidDateFromat)?;
 
-        let (year, month, day, hour, min, sec) = (
-            parse_comp(&matched, "year", InvalidDateFormat::NoYear)?,
-            parse_comp(&matched, "month", InvalidDateFormat::NoMonth)?,
-            parse_comp(&matched, "day", InvalidDateFormat::NoDay)?,
+        let (year, month, day) = match matched.name("relative") {
+            Some(relative) => {
+                let relative = relative.as_str();
+                let date = if relative.eq_ignore_ascii_case(TOMORROW) {
+                    today.succ_opt().ok_or(InvalidDateFormat::NoValidDate)?
+                } else {
+                    debug_assert!(relative.eq_ignore_ascii_case(TODAY));
+                    today
+                };
+                (date.year(), date.month(), date.day())
+            }
+            None => (
+                parse_comp(&matched, "year", InvalidDateFormat::NoYear)?,
+                parse_comp(&matched, "month", InvalidDateFormat::NoMonth)?,
+                parse_comp(&matched, "day", InvalidDateFormat::NoDay)?,
+            ),
+        };
+        let (hour, min) = (
             parse_comp(&matched, "hours", InvalidDateFormat::NoHours)?,
             parse_comp(&matched, "minutes", InvalidDateFormat::NoMonth)?,
-            parse_comp(&matched, "seconds", InvalidDateFormat::NoSeconds)?,
         );
+        let sec = match matched.name("seconds") {
+            Some(_) => parse_comp(&matched, "seconds", InvalidDateFormat::NoSeconds)?,
+            None => 0,
+        };
 
         if day > 31 {
             return Err(InvalidDateFormat::NoDay);
@@ -99,17 +189,43 @@ impl FromStr for ParsedDate {
             return Err(InvalidDateFormat::NoSeconds);
         }
 
-        match Utc.with_ymd_and_hms(year, month, day, hour, min, sec) {
-            LocalResult::Single(date) => Ok(ParsedDate(date)),
-            LocalResult::Ambiguous(_, _) => Err(InvalidDateFormat::Ambiguous),
-            LocalResult::None => Err(InvalidDateFormat::NoValidDate),
-        }
+        let date = NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|date| date.and_hms_opt(hour, min, sec))
+            .ok_or(InvalidDateFormat::NoValidDate)?;
+        let zone = match (matched.name("offset"), matched.name("zone")) {
+            (Some(offset), _) => Some(DateZone::Offset(parse_offset(offset.as_str())?)),
+            (None, Some(zone)) => Some(DateZone::Named(
+                zone.as_str()
+                    .parse()
+                    .map_err(|_| InvalidDateFormat::NoTimeZone)?,
+            )),
+            (None, None) => None,
+        };
+        Ok(ParsedDate { date, zone })
     }
 }
 
+fn parse_offset(offset: &str) -> Result<FixedOffset, InvalidDateFormat> {
+    if offset == "Z" {
+        return Ok(FixedOffset::east_opt(0).unwrap());
+    }
+    let (sign, digits) = offset.split_at(1);
+    let digits = digits.replace(':', "");
+    let (hours, mins) = digits.split_at(2);
+    let (hours, mins): (i32, i32) = (
+        hours.parse().map_err(|_| InvalidDateFormat::NoOffset)?,
+        mins.parse().map_err(|_| InvalidDateFormat::NoOffset)?,
+    );
+    if mins > 59 {
+        return Err(InvalidDateFormat::NoOffset);
+    }
+    let secs = (hours * 3600 + mins * 60) * if sign == "-" { -1 } else { 1 };
+    FixedOffset::east_opt(secs).ok_or(InvalidDateFormat::NoOffset)
+}
+
 #[cfg(test)]
 mod testing {
-    use chrono::{TimeZone, Utc};
+    use chrono::Utc;
 
     use super::*;
 
@@ -121,38 +237,50 @@ mod testing {
         }
         assert_case(
             "2022-02-01 02:04:02",
-            Ok(ParsedDate(
-                Utc.with_ymd_and_hms(2022, 2, 1, 2, 4, 2).unwrap(),
+            Ok(ParsedDate::from(
+                Utc.with_ymd_and_hms(2022, 2, 1, 2, 4, 2)
+                    .unwrap()
+                    .naive_utc(),
             )),
         );
         assert_case(
             "2022-2-1 2:4:2",
-            Ok(ParsedDate(
-                Utc.with_ymd_and_hms(2022, 2, 1, 2, 4, 2).unwrap(),
+            Ok(ParsedDate::from(
+                Utc.with_ymd_and_hms(2022, 2, 1, 2, 4, 2)
+                    .unwrap()
+                    .naive_utc(),
             )),
         );
         assert_case(
             "2022-10-15 00:00:00",
-            Ok(ParsedDate(
-                Utc.with_ymd_and_hms(2022, 10, 15, 0, 0, 0).unwrap(),
+            Ok(ParsedDate::from(
+                Utc.with_ymd_and_hms(2022, 10, 15, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
             )),
         );
         assert_case(
             "2022-10-15__00:00:00",
-            Ok(ParsedDate(
-                Utc.with_ymd_and_hms(2022, 10, 15, 0, 0, 0).unwrap(),
+            Ok(ParsedDate::from(
+                Utc.with_ymd_and_hms(2022, 10, 15, 0, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
             )),
         );
         assert_case(
             "2022-12-31__23:59:59",
-            Ok(ParsedDate(
-                Utc.with_ymd_and_hms(2022, 12, 31, 23, 59, 59).unwrap(),
+            Ok(ParsedDate::from(
+                Utc.with_ymd_and_hms(2022, 12, 31, 23, 59, 59)
+                    .unwrap()
+                    .naive_utc(),
             )),
         );
         assert_case(
             "2022-01-01 00:59:59",
-            Ok(ParsedDate(
-                Utc.with_ymd_and_hms(2022, 1, 1, 0, 59, 59).unwrap(),
+            Ok(ParsedDate::from(
+                Utc.with_ymd_and_hms(2022, 1, 1, 0, 59, 59)
+                    .unwrap()
+                    .naive_utc(),
             )),
         );
         assert_case("2022-12-32__23:59:59", Err(InvalidDateFormat::NoDay));
@@ -162,4 +290,101 @@ mod testing {
         assert_case("2022-01-01 23:60:59", Err(InvalidDateFormat::NoMinutes));
         assert_case("2022-01-01 23:59:60", Err(InvalidDateFormat::NoSeconds));
     }
+
+    #[test]
+    fn without_seconds_and_relative_days() {
+        fn assert_case(input: &str, expected: Result<ParsedDate, InvalidDateFormat>) {
+            let today = NaiveDate::from_ymd_opt(2026, 12, 31).unwrap();
+            let actual = ParsedDate::parse_with_today(input, today);
+            assert_eq!(expected, actual, "Given input: {}", input);
+        }
+        assert_case(
+            "2026-12-24 18:00",
+            Ok(ParsedDate::from(
+                Utc.with_ymd_and_hms(2026, 12, 24, 18, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+            )),
+        );
+        assert_case(
+            "today 09:30:15",
+            Ok(ParsedDate::from(
+                Utc.with_ymd_and_hms(2026, 12, 31, 9, 30, 15)
+                    .unwrap()
+                    .naive_utc(),
+            )),
+        );
+        assert_case(
+            "Tomorrow 09:00",
+            Ok(ParsedDate::from(
+                Utc.with_ymd_and_hms(2027, 1, 1, 9, 0, 0)
+                    .unwrap()
+                    .naive_utc(),
+            )),
+        );
+        assert_case("tomorrow", Err(InvalidDateFormat::InvalidDateFromat));
+        assert_case("tomorrow 24:00", Err(InvalidDateFormat::NoHours));
+    }
+
+    #[test]
+    fn explicit_zone_or_offset() {
+        fn assert_case(input: &str, expected: Result<&str, InvalidDateFormat>) {
+            let actual = input
+                .parse::<ParsedDate>()
+                .and_then(|date| date.to_utc_in(&chrono_tz::America::New_York))
+                .map(|date| date.to_rfc3339());
+            assert_eq!(
+                expected.map(ToOwned::to_owned),
+                actual,
+                "Given input: {}",
+                input
+            );
+        }
+        assert_case("2024-05-01 08:00", Ok("2024-05-01T12:00:00+00:00"));
+        assert_case(
+            "2024-05-01 08:00 Europe/Berlin",
+            Ok("2024-05-01T06:00:00+00:00"),
+        );
+        assert_case("2024-05-01 08:00 UTC", Ok("2024-05-01T08:00:00+00:00"));
+        assert_case("2024-05-01 08:00Z", Ok("2024-05-01T08:00:00+00:00"));
+        assert_case("2024-05-01 08:00:30+02:00", Ok("2024-05-01T06:00:30+00:00"));
+        assert_case("2024-05-01 08:00 -0130", Ok("2024-05-01T09:30:00+00:00"));
+        assert_case(
+            "2024-05-01 08:00 Mars/Olympus",
+            Err(InvalidDateFormat::NoTimeZone),
+        );
+        assert_case("2024-05-01 08:00+02:60", Err(InvalidDateFormat::NoOffset));
+        assert_case(
+            "2024-03-31 02:30 Europe/Berlin",
+            Err(InvalidDateFormat::NoValidDate),
+        );
+        assert_case(
+            "2024-10-27 02:30 Europe/Berlin",
+            Err(InvalidDateFormat::Ambiguous),
+        );
+    }
+
+    #[test]
+    fn convert_into_utc() {
+        fn assert_case(input: &str, expected: Result<&str, InvalidDateFormat>) {
+            let date: ParsedDate = input.parse().unwrap();
+            let actual = UtcDateTime::try_from(date).map(|date| date.to_rfc3339());
+            assert_eq!(
+                expected.map(str::to_string),
+                actual,
+                "Given input: {}",
+                input
+            );
+        }
+        assert_case("2024-05-01 08:00", Ok("2024-05-01T08:00:00+00:00"));
+        assert_case("2024-05-01 08:00+02:00", Ok("2024-05-01T06:00:00+00:00"));
+        assert_case(
+            "2024-10-27 02:30 Europe/Berlin",
+            Err(InvalidDateFormat::Ambiguous),
+        );
+        assert_case(
+            "2024-03-31 02:30 Europe/Berlin",
+            Err(InvalidDateFormat::NoValidDate),
+        );
+    }
 }
@@ -0,0 +1,238 @@
+//! Deterministic time for tests of code built on top of this crate.
+//!
+//! Available with the cargo feature `testing`.
+//! Clocks are created with the time implementation of a [`VirtualClock`].
+//! Afterwards the virtual clock is moved forward by hand, by a [`Timeline`]
+//! or until a count down finishes.
+
+use chrono::TimeDelta;
+
+use crate::timing::{
+    mocking_time::MockTimeImpl, Clock, ClockTable, ClockType, CountDown, TimeImpl, Timer,
+    UtcDateTime,
+};
+
+pub use crate::timing::test_utils::new_utc_moment;
+
+/// Upper bound of steps taken while waiting for a count down to finish.
+/// Guards against count downs which repeat forever.
+const MAX_STEPS_UNTIL_FINISHED: usize = 10_000;
+
+/// Shared moment which only moves if told so
+#[derive(Debug, Clone)]
+pub struct VirtualClock(MockTimeImpl);
+
+impl VirtualClock {
+    pub fn new(start: UtcDateTime) -> Self {
+        Self(MockTimeImpl::new(start))
+    }
+
+    /// Time implementation for new clocks which follow this virtual clock
+    pub fn time_impl(&self) -> MockTimeImpl {
+        self.0.clone()
+    }
+
+    pub fn now(&self) -> UtcDateTime {
+        self.0.now()
+    }
+
+    pub fn advance(&self, by: TimeDelta) {
+        self.0.add_to_now(by);
+    }
+
+    pub fn set(&self, moment: UtcDateTime) {
+        self.0.set_now(moment);
+    }
+
+    /// Moves forward until the given count down is finished and returns the moment it finished.
+    /// The count down has to follow this virtual clock.
+    ///
+    /// None if the count down never finishes, because it is paused or repeats forever.
+    pub fn advance_until_finished<T>(&self, count_down: &CountDown<T>) -> Option<UtcDateTime>
+    where
+        T: TimeImpl + Default,
+    {
+        for _ in 0..MAX_STEPS_UNTIL_FINISHED {
+            if let Some(finished_at) = count_down.finished_at() {
+                self.set(finished_at.max(self.now()));
+                return Some(finished_at);
+            }
+            let step = match (count_down.is_paused(), count_down.starts_in()) {
+                (true, _) => return None,
+                (false, Some(starts_in)) => TimeDelta::from(starts_in),
+                (false, None) => match count_down.target() {
+                    Some(target) => target - self.now(),
+                    None => count_down.left_time().into(),
+                },
+            };
+            // A step of zero would not get any closer to the end.
+            self.advance(step.max(TimeDelta::seconds(1)));
+        }
+        None
+    }
+
+    /// Same as [`Self::advance_until_finished`] for the count down with the given name.
+    /// None if there is no such count down.
+    pub fn advance_until_count_down_finishes(
+        &self,
+        table: &ClockTable<MockTimeImpl>,
+        name: &str,
+    ) -> Option<UtcDateTime> {
+        let count_down = table
+            .get(ClockType::CountDown, name)
+            .and_then(Clock::as_count_down)?;
+        self.advance_until_finished(count_down)
+    }
+}
+
+type Step<'a, S> = Box<dyn FnOnce(&mut S) + 'a>;
+
+/// Steps which are played at given moments on a virtual clock
+pub struct Timeline<'a, S> {
+    clock: VirtualClock,
+    last_moment: UtcDateTime,
+    steps: Vec<(UtcDateTime, Step<'a, S>)>,
+}
+
+impl<'a, S> Timeline<'a, S> {
+    pub fn new(clock: &VirtualClock) -> Self {
+        Self {
+            clock: clock.clone(),
+            last_moment: clock.now(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Plays the step at the given moment
+    pub fn at(mut self, moment: UtcDateTime, step: impl FnOnce(&mut S) + 'a) -> Self {
+        self.last_moment = moment;
+        self.steps.push((moment, Box::new(step)));
+        self
+    }
+
+    /// Plays the step after the given delay counted from the previous step
+    pub fn after(self, delay: TimeDelta, step: impl FnOnce(&mut S) + 'a) -> Self {
+        let moment = self.last_moment + delay;
+        self.at(moment, step)
+    }
+
+    /// Plays all steps ordered by their moments. Steps at the same moment keep their order.
+    /// The virtual clock stays at the moment of the last step.
+    pub fn run(mut self, state: &mut S) {
+        self.steps.sort_by_key(|(moment, _)| *moment);
+        for (moment, step) in self.steps {
+            self.clock.set(moment);
+            step(state);
+        }
+    }
+}
+
+/// Text with one line per clock of the table at the current moment.
+/// Moments are written in UTC so the text does not depend on the local time zone.
+pub fn render_table<T>(table: &ClockTable<T>) -> String
+where
+    T: TimeImpl + Default,
+{
+    table
+        .all(None)
+        .map(|(name, clock)| render_clock(name, clock))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Text of [`render_table`] for every given instant, each below a line with the instant.
+/// The virtual clock stays at the last instant.
+///
+/// Instants should not lie before moments which the clocks have already recorded.
+/// Otherwise pauses and resumes from the future of an instant are taken into account.
+pub fn render_table_at(
+    clock: &VirtualClock,
+    table: &ClockTable<MockTimeImpl>,
+    instants: impl IntoIterator<Item = UtcDateTime>,
+) -> String {
+    instants
+        .into_iter()
+        .map(|instant| {
+            clock.set(instant);
+            format!("[{}]\n{}", instant.naive_utc(), render_table(table))
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+fn render_clock<T>(name: &str, clock: &Clock<T>) -> String
+where
+    T: TimeImpl + Default,
+{
+    let mut fields = vec![
+        format!("{} `{}`", clock.clock_type(), name),
+        clock.state().to_string(),
+        format!("passed {}", clock.passed()),
+        format!("paused {}", clock.paused_time()),
+    ];
+    match clock {
+        Clock::Stopwatch(_) => (),
+        Clock::CountDown(count_down) => fields.push(format!("left {}", count_down.left_time())),
+        Clock::Pomodoro(pomodoro) => fields.push(format!(
+            "{} left {}",
+            pomodoro.current_phase(),
+            pomodoro.left_in_phase()
+        )),
+        Clock::Sequence(sequence) => fields.push(match sequence.current_stage() {
+            Some((_, stage)) => format!("{} left {}", stage.label(), sequence.left_in_stage()),
+            None => format!("left {}", sequence.total_left()),
+        }),
+        Clock::Budget(budget) => fields.push(format!("left {}", budget.left_in_period())),
+    }
+    fields.join(" | ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::timing::{ClockDuration, Stopwatch};
+
+    use super::*;
+
+    #[test]
+    fn play_timeline_and_render_table() {
+        let clock = VirtualClock::new(new_utc_moment("2024-05-01 08:00:00"));
+        let mut table = ClockTable::default();
+        table
+            .add(
+                "coding".to_string(),
+                Stopwatch::new_with_impl(clock.time_impl()),
+            )
+            .unwrap();
+        let tea = CountDown::new_with_impl(
+            clock.time_impl(),
+            ClockDuration::new_secs_mins_hours(None, Some(10), None),
+        );
+        table.add("tea".to_string(), tea).unwrap();
+
+        let mut state = (table, Vec::new());
+        Timeline::new(&clock)
+            .after(
+                TimeDelta::minutes(3),
+                |(table, _): &mut (ClockTable<_>, Vec<String>)| {
+                    table.mut_count_down("tea").unwrap().pause()
+                },
+            )
+            .after(TimeDelta::minutes(1), |(table, rendered)| {
+                rendered.push(render_table(table))
+            })
+            .after(TimeDelta::minutes(1), |(table, _)| {
+                table.mut_count_down("tea").unwrap().resume()
+            })
+            .run(&mut state);
+        let (table, mut rendered) = state;
+        let finished_at = clock.advance_until_count_down_finishes(&table, "tea");
+        pretty_assertions::assert_eq!(Some(new_utc_moment("2024-05-01 08:12:00")), finished_at);
+
+        rendered.push(render_table_at(
+            &clock,
+            &table,
+            ["2024-05-01 08:12:00", "2024-05-01 08:20:00"].map(new_utc_moment),
+        ));
+        insta::assert_snapshot!(rendered.join("\n\n"));
+    }
+}
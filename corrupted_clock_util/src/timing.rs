@@ -1,22 +1,60 @@
-pub use clock_duration::ClockDuration;
+pub use adjustment::Adjustment;
+pub use budget::{Budget, BudgetPeriod, CarryOver, RefillPeriod};
+pub use clock::Clock;
+pub use clock_duration::{
+    ClockDuration, DurationFormat, DurationStyle, InvalidClockDurationString, Precision,
+    StyledDuration,
+};
+pub use clock_state::ClockState;
 pub use clock_table::ClockTable;
+pub use clock_type::ClockType;
 pub use count_down::CountDown;
+pub use exclusive_mode::ExclusiveMode;
+pub use lap::Lap;
+pub use pomodoro::{PhaseRecord, Pomodoro, PomodoroConfig, PomodoroPhase};
+pub use repeat_policy::RepeatPolicy;
+pub use run::{Run, RunStats};
+pub use segment_edit::{InvalidSegmentEdit, SegmentEdit};
+pub use sequence::{Sequence, Stage, StageProgress, StageStatus};
 use serde::{Deserialize, Serialize};
+pub use settings::Settings;
+pub use speed::{InvalidSpeed, RateChange, Speed};
 pub use stopwatch::Stopwatch;
+pub use time_change::TimeChange;
+pub use time_jump::{TimeJump, TimeJumpPolicy};
+pub use time_segment::TimeSegment;
 
 pub mod mocking_time;
-#[cfg(test)]
+#[cfg(any(test, feature = "testing"))]
 pub mod test_utils;
 
+mod adjustment;
+mod budget;
+mod clock;
 mod clock_duration;
+mod clock_state;
 mod clock_table;
+mod clock_type;
 mod count_down;
+mod exclusive_mode;
+mod lap;
+mod pomodoro;
+mod repeat_policy;
+mod run;
+mod segment_edit;
+mod sequence;
+mod settings;
+mod speed;
 mod stopwatch;
+mod time_change;
+mod time_jump;
+mod time_segment;
 
 pub type UtcDateTime = DateTime<Utc>;
 pub type ChronoDuration = chrono::Duration;
 
 use chrono::{DateTime, Utc};
+pub use chrono_tz::Tz;
 use thiserror::Error;
 
 #[derive(Default, Serialize, Deserialize)]
@@ -24,10 +62,14 @@ pub struct UtcTimeImpl;
 #[derive(Default, Serialize, Deserialize)]
 pub struct LocalTimeImpl;
 
-#[derive(Debug, Error, Clone, Copy)]
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
 #[error("'{0}' as a start date must not be in the future")]
 pub struct InvalidDateInFuture(UtcDateTime);
 
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("'{0}' as the date of an alarm must be in the future")]
+pub struct InvalidAlarmDate(UtcDateTime);
+
 pub fn validate_if_date_is_not_in_future(
     time: &impl TimeImpl,
     to_check: UtcDateTime,
@@ -51,13 +93,23 @@ pub trait Timer {
     fn pause(&mut self);
     fn resume(&mut self);
     fn reset(&mut self);
+    /// All intervals in which the clock was running, ordered from the oldest to the newest
+    fn running_segments(&self) -> Vec<TimeSegment>;
+    /// Adds a signed amount of time to the passed time and records it as an adjustment
+    fn adjust(&mut self, amount: ClockDuration, reason: Option<String>);
+    fn adjustments(&self) -> &[Adjustment];
+    /// Time until the scheduled start. None if the clock has already started.
+    fn starts_in(&self) -> Option<ClockDuration>;
+    fn state(&self) -> ClockState;
+    /// Moment at which the clock finished. Only count downs finish.
+    fn finished_at(&self) -> Option<UtcDateTime>;
 }
 
 pub trait TimeImpl {
     fn now(&self) -> UtcDateTime;
 }
 
-impl<'a, T> TimeImpl for &'a T
+impl<T> TimeImpl for &T
 where
     T: TimeImpl,
 {
@@ -71,3 +123,29 @@ impl TimeImpl for UtcTimeImpl {
         Utc::now()
     }
 }
+
+impl<T> TimeImpl for std::sync::Arc<T>
+where
+    T: TimeImpl,
+{
+    fn now(&self) -> UtcDateTime {
+        self.as_ref().now()
+    }
+}
+
+// Clocks can be moved and shared between threads whenever their time implementation can be.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    const fn assert_clocks_send_sync<T: Default + Send + Sync>() {
+        assert_send_sync::<Stopwatch<T>>();
+        assert_send_sync::<CountDown<T>>();
+        assert_send_sync::<Pomodoro<T>>();
+        assert_send_sync::<Sequence<T>>();
+        assert_send_sync::<Budget<T>>();
+        assert_send_sync::<Clock<T>>();
+        assert_send_sync::<ClockTable<T>>();
+    }
+    assert_clocks_send_sync::<UtcTimeImpl>();
+    assert_clocks_send_sync::<mocking_time::MockTimeImpl>();
+    assert_clocks_send_sync::<mocking_time::FixedTimeImpl>();
+};
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ClockDuration, UtcDateTime};
+
+/// Manual correction of the passed time of a clock
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Adjustment {
+    recorded_at: UtcDateTime,
+    amount: ClockDuration,
+    reason: Option<String>,
+}
+
+impl Adjustment {
+    pub fn new(recorded_at: UtcDateTime, amount: ClockDuration, reason: Option<String>) -> Self {
+        Self {
+            recorded_at,
+            amount,
+            reason,
+        }
+    }
+
+    pub fn recorded_at(&self) -> UtcDateTime {
+        self.recorded_at
+    }
+
+    /// Negative if time was subtracted
+    pub fn amount(&self) -> ClockDuration {
+        self.amount
+    }
+
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    pub(crate) fn sum_amounts<'a>(
+        adjustments: impl IntoIterator<Item = &'a Adjustment>,
+    ) -> ClockDuration {
+        adjustments
+            .into_iter()
+            .fold(ClockDuration::default(), |sum, next| sum + next.amount)
+    }
+}
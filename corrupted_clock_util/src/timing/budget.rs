@@ -0,0 +1,666 @@
+use chrono::{Datelike, Days, Months, NaiveDate, NaiveTime, TimeDelta, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Adjustment, ClockDuration, ClockState, InvalidSegmentEdit, Lap, SegmentEdit, Stopwatch,
+    TimeImpl, TimeSegment, Timer, UtcDateTime, UtcTimeImpl,
+};
+
+/// Calendar period after which the allowance of a budget is refilled.
+/// Periods start at midnight in the time zone of the budget or in UTC if it has none.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RefillPeriod {
+    Daily,
+    /// Weeks start on Monday
+    #[default]
+    Weekly,
+    Monthly,
+}
+
+impl RefillPeriod {
+    /// First day of the period which contains the given day
+    fn first_day(self, day: NaiveDate) -> NaiveDate {
+        match self {
+            RefillPeriod::Daily => day,
+            RefillPeriod::Weekly => day - Days::new(day.weekday().num_days_from_monday().into()),
+            RefillPeriod::Monthly => day.with_day(1).expect("Every month has a first day"),
+        }
+    }
+
+    /// First day of the period after the one starting at the given day
+    fn next_first_day(self, first_day: NaiveDate) -> NaiveDate {
+        match self {
+            RefillPeriod::Daily => first_day + Days::new(1),
+            RefillPeriod::Weekly => first_day + Days::new(7),
+            RefillPeriod::Monthly => first_day + Months::new(1),
+        }
+    }
+
+    /// Number of periods from the one starting at the first day up to the one starting at the later day
+    fn periods_between(self, first_day: NaiveDate, later_first_day: NaiveDate) -> u32 {
+        let periods = match self {
+            RefillPeriod::Daily => (later_first_day - first_day).num_days(),
+            RefillPeriod::Weekly => (later_first_day - first_day).num_weeks(),
+            RefillPeriod::Monthly => {
+                let months = |day: NaiveDate| i64::from(day.year()) * 12 + i64::from(day.month0());
+                months(later_first_day) - months(first_day)
+            }
+        };
+        periods.try_into().unwrap_or_default()
+    }
+}
+
+impl std::fmt::Display for RefillPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RefillPeriod::Daily => f.write_str("daily"),
+            RefillPeriod::Weekly => f.write_str("weekly"),
+            RefillPeriod::Monthly => f.write_str("monthly"),
+        }
+    }
+}
+
+/// What a period passes on to the next one
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CarryOver {
+    /// Every period starts with the allowance alone
+    #[default]
+    None,
+    /// Unused allowance is added to the next period
+    Unused,
+    /// Unused allowance is added to the next period and overdrawn allowance is taken from it
+    All,
+}
+
+impl std::fmt::Display for CarryOver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CarryOver::None => f.write_str("none"),
+            CarryOver::Unused => f.write_str("unused"),
+            CarryOver::All => f.write_str("all"),
+        }
+    }
+}
+
+/// Usage of a budget within one of its periods
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct BudgetPeriod {
+    start: UtcDateTime,
+    end: UtcDateTime,
+    available: ClockDuration,
+    used: ClockDuration,
+    carried: ClockDuration,
+}
+
+impl BudgetPeriod {
+    pub fn start(&self) -> UtcDateTime {
+        self.start
+    }
+
+    /// Moment at which the allowance is refilled
+    pub fn end(&self) -> UtcDateTime {
+        self.end
+    }
+
+    /// Allowance with what was carried over from the previous period
+    pub fn available(&self) -> ClockDuration {
+        self.available
+    }
+
+    pub fn used(&self) -> ClockDuration {
+        self.used
+    }
+
+    /// Allowance passed on from the previous period. Negative if it was overdrawn.
+    pub fn carried(&self) -> ClockDuration {
+        self.carried
+    }
+
+    /// Negative if more than the available allowance was used
+    pub fn left(&self) -> ClockDuration {
+        self.available - self.used
+    }
+}
+
+/// Allowance of time which refills on a calendar schedule and is used up while running
+#[derive(Serialize, Deserialize)]
+pub struct Budget<T = UtcTimeImpl>
+where
+    T: Default,
+{
+    stopwatch: Stopwatch<T>,
+    allowance: ClockDuration,
+    #[serde(default)]
+    refill: RefillPeriod,
+    #[serde(default)]
+    carry_over: CarryOver,
+    /// Periods which ended by a refill or a reset, oldest first.
+    /// They are kept as they were once closed, so later changes to the stopwatch do not alter them.
+    #[serde(default)]
+    closed_periods: Vec<BudgetPeriod>,
+    /// Carried over into the period after the closed ones
+    #[serde(default)]
+    carried: ClockDuration,
+}
+
+impl<T> std::fmt::Debug for Budget<T>
+where
+    T: Default,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Budget")
+            .field("stopwatch", &self.stopwatch)
+            .field("allowance", &self.allowance)
+            .field("refill", &self.refill)
+            .field("carry_over", &self.carry_over)
+            .field("closed_periods", &self.closed_periods)
+            .field("carried", &self.carried)
+            .finish()
+    }
+}
+
+impl Budget {
+    pub fn new(allowance: ClockDuration, refill: RefillPeriod, carry_over: CarryOver) -> Self {
+        Self::from_stopwatch(Stopwatch::new(), allowance, refill, carry_over)
+    }
+
+    /// Budget which starts to be used up at the given moment
+    pub fn new_scheduled(
+        allowance: ClockDuration,
+        refill: RefillPeriod,
+        carry_over: CarryOver,
+        start: UtcDateTime,
+    ) -> Self {
+        Self::from_stopwatch(
+            Stopwatch::new_scheduled(start),
+            allowance,
+            refill,
+            carry_over,
+        )
+    }
+}
+
+impl<T> Budget<T>
+where
+    T: TimeImpl + Default,
+{
+    pub fn new_with_impl(
+        time_impl: T,
+        allowance: ClockDuration,
+        refill: RefillPeriod,
+        carry_over: CarryOver,
+    ) -> Self {
+        Self::from_stopwatch(
+            Stopwatch::new_with_impl(time_impl),
+            allowance,
+            refill,
+            carry_over,
+        )
+    }
+
+    fn from_stopwatch(
+        stopwatch: Stopwatch<T>,
+        allowance: ClockDuration,
+        refill: RefillPeriod,
+        carry_over: CarryOver,
+    ) -> Self {
+        Self {
+            stopwatch,
+            allowance,
+            refill,
+            carry_over,
+            closed_periods: Vec::new(),
+            carried: ClockDuration::default(),
+        }
+    }
+
+    /// Time granted anew in every period
+    pub fn allowance(&self) -> ClockDuration {
+        self.allowance
+    }
+
+    pub fn refill(&self) -> RefillPeriod {
+        self.refill
+    }
+
+    pub fn carry_over(&self) -> CarryOver {
+        self.carry_over
+    }
+
+    /// Every period from the one the budget started in up to the current one, oldest first.
+    /// Empty while the budget was neither reset nor started yet.
+    pub fn periods(&self) -> Vec<BudgetPeriod> {
+        let mut periods = self.closed_periods.clone();
+        periods.extend(self.open_periods());
+        periods
+    }
+
+    /// Periods after the closed ones up to the current one, oldest first.
+    /// Only the current one is left after the due periods were closed.
+    fn open_periods(&self) -> Vec<BudgetPeriod> {
+        if self.stopwatch.is_scheduled() {
+            return Vec::new();
+        }
+        let now = self.stopwatch.now();
+        let mut start = self.first_open_start();
+        let mut carried = self.carried;
+        let mut periods = Vec::new();
+        loop {
+            let period = self.period_from(start, carried);
+            periods.push(period);
+            if now < period.end {
+                return periods;
+            }
+            carried = self.carried_from(&period);
+            start = period.end;
+        }
+    }
+
+    /// Start of the period after the closed ones
+    fn first_open_start(&self) -> UtcDateTime {
+        self.closed_periods.last().map_or_else(
+            || {
+                let first_day = self
+                    .refill
+                    .first_day(self.day_of(self.stopwatch.start_moment()));
+                self.start_of_day(first_day)
+            },
+            BudgetPeriod::end,
+        )
+    }
+
+    /// Period which starts at the given moment and ends at the next refill
+    fn period_from(&self, start: UtcDateTime, carried: ClockDuration) -> BudgetPeriod {
+        let first_day = self.refill.first_day(self.day_of(start));
+        let end = self.start_of_day(self.refill.next_first_day(first_day));
+        BudgetPeriod {
+            start,
+            end,
+            available: self.allowance + carried,
+            used: self.stopwatch.passed_between(start, end),
+            carried,
+        }
+    }
+
+    /// Moves the periods, which were refilled since, into the closed ones
+    pub(crate) fn close_due_periods(&mut self) {
+        let mut open = self.open_periods();
+        _ = open.pop();
+        if let Some(last) = open.last() {
+            self.carried = self.carried_from(last);
+        }
+        self.closed_periods.extend(open);
+    }
+
+    fn carried_from(&self, period: &BudgetPeriod) -> ClockDuration {
+        match self.carry_over {
+            CarryOver::None => ClockDuration::default(),
+            CarryOver::Unused => period.left().max(ClockDuration::default()),
+            CarryOver::All => period.left(),
+        }
+    }
+
+    /// None while the budget is scheduled.
+    /// Periods since the closed ones are skipped without going through each of them
+    /// unless the budget was used in them.
+    pub fn current_period(&self) -> Option<BudgetPeriod> {
+        if self.stopwatch.is_scheduled() {
+            return None;
+        }
+        let open_start = self.first_open_start();
+        let current_first_day = self.refill.first_day(self.day_of(self.stopwatch.now()));
+        let current_start = self.start_of_day(current_first_day);
+        if current_start <= open_start {
+            return Some(self.period_from(open_start, self.carried));
+        }
+        let carried = match self.carry_over {
+            CarryOver::None => ClockDuration::default(),
+            // The allowance of every unused period is carried over as a whole.
+            // The carry into the first open period is never negative for "unused".
+            _ if self
+                .stopwatch
+                .passed_between(open_start, current_start)
+                .is_zero() =>
+            {
+                let open_first_day = self.refill.first_day(self.day_of(open_start));
+                let skipped = self
+                    .refill
+                    .periods_between(open_first_day, current_first_day);
+                self.carried + self.allowance * skipped
+            }
+            _ => return self.open_periods().pop(),
+        };
+        Some(self.period_from(current_start, carried))
+    }
+
+    /// Allowance left in the current period. Negative if it is overdrawn.
+    pub fn left_in_period(&self) -> ClockDuration {
+        self.current_period()
+            .map_or(self.allowance, |period| period.left())
+    }
+
+    pub fn laps(&self) -> &[Lap] {
+        self.stopwatch.laps()
+    }
+
+    pub fn lap(&mut self) -> Lap {
+        self.stopwatch.lap()
+    }
+
+    pub fn edit(&mut self, edit: SegmentEdit) -> Result<(), InvalidSegmentEdit> {
+        self.close_due_periods();
+        self.stopwatch.edit(edit)
+    }
+
+    /// Calendar day of the moment in the time zone of the budget or in UTC
+    fn day_of(&self, moment: UtcDateTime) -> NaiveDate {
+        match self.stopwatch.time_zone() {
+            Some(zone) => moment.with_timezone(&zone).date_naive(),
+            None => moment.date_naive(),
+        }
+    }
+
+    fn start_of_day(&self, day: NaiveDate) -> UtcDateTime {
+        match self.stopwatch.time_zone() {
+            Some(zone) => start_of_day_in(&zone, day),
+            None => start_of_day_in(&Utc, day),
+        }
+    }
+}
+
+impl<T> Budget<T>
+where
+    T: Default,
+{
+    pub fn stopwatch(&self) -> &Stopwatch<T> {
+        &self.stopwatch
+    }
+
+    pub(crate) fn mut_stopwatch(&mut self) -> &mut Stopwatch<T> {
+        &mut self.stopwatch
+    }
+}
+
+/// First moment of the day. Some days do not start at midnight
+/// since the clocks are put forward at midnight for daylight saving time.
+fn start_of_day_in<Z: TimeZone>(zone: &Z, day: NaiveDate) -> UtcDateTime {
+    let midnight = day.and_time(NaiveTime::MIN);
+    (0..3)
+        .find_map(|hours| {
+            zone.from_local_datetime(&(midnight + TimeDelta::hours(hours)))
+                .earliest()
+        })
+        .map(|start| start.to_utc())
+        .unwrap_or_else(|| Utc.from_utc_datetime(&midnight))
+}
+
+impl<T> Timer for Budget<T>
+where
+    T: TimeImpl + Default,
+{
+    fn created_at(&self) -> UtcDateTime {
+        self.stopwatch.created_at()
+    }
+
+    fn start_moment(&self) -> UtcDateTime {
+        self.stopwatch.start_moment()
+    }
+
+    fn last_resumed_at(&self) -> Option<UtcDateTime> {
+        self.stopwatch.last_resumed_at()
+    }
+
+    fn last_paused_at(&self) -> Option<UtcDateTime> {
+        self.stopwatch.last_paused_at()
+    }
+
+    fn passed(&self) -> ClockDuration {
+        self.stopwatch.passed()
+    }
+
+    fn paused_time(&self) -> ClockDuration {
+        self.stopwatch.paused_time()
+    }
+
+    fn is_paused(&self) -> bool {
+        self.stopwatch.is_paused()
+    }
+
+    fn pause(&mut self) {
+        self.stopwatch.pause();
+    }
+
+    fn resume(&mut self) {
+        self.stopwatch.resume();
+    }
+
+    /// Closes the current period at the reset. The budget starts anew without anything carried over.
+    fn reset(&mut self) {
+        self.close_due_periods();
+        let now = self.stopwatch.now();
+        if let Some(mut current) = self
+            .open_periods()
+            .pop()
+            .filter(|period| period.start < now)
+        {
+            current.end = now;
+            self.closed_periods.push(current);
+        }
+        self.carried = ClockDuration::default();
+        self.stopwatch.reset();
+    }
+
+    fn running_segments(&self) -> Vec<TimeSegment> {
+        self.stopwatch.running_segments()
+    }
+
+    fn adjust(&mut self, amount: ClockDuration, reason: Option<String>) {
+        self.close_due_periods();
+        self.stopwatch.adjust(amount, reason);
+    }
+
+    fn adjustments(&self) -> &[Adjustment] {
+        self.stopwatch.adjustments()
+    }
+
+    fn starts_in(&self) -> Option<ClockDuration> {
+        self.stopwatch.starts_in()
+    }
+
+    fn state(&self) -> ClockState {
+        self.stopwatch.state()
+    }
+
+    fn finished_at(&self) -> Option<UtcDateTime> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use chrono::TimeDelta;
+
+    use crate::timing::{
+        mocking_time::MockTimeImpl,
+        test_utils::{add_to_now, new_utc_moment},
+    };
+
+    use super::*;
+
+    fn support_duty(carry_over: CarryOver) -> (Budget<MockTimeImpl>, MockTimeImpl) {
+        // A wednesday
+        let time_impl = MockTimeImpl::new(new_utc_moment("2024-05-01 08:00:00"));
+        let mut budget = Budget::new_with_impl(
+            time_impl.clone(),
+            ClockDuration::new_secs_mins_hours(Some(5), None, None),
+            RefillPeriod::Weekly,
+            carry_over,
+        );
+        budget.mut_stopwatch().set_time_zone(Some(chrono_tz::UTC));
+        (budget, time_impl)
+    }
+
+    fn use_across_two_weeks(budget: &mut Budget<MockTimeImpl>, setter: &mut MockTimeImpl) {
+        add_to_now(setter, TimeDelta::hours(7));
+        budget.pause();
+        setter.set_now(new_utc_moment("2024-05-06 09:00:00"));
+        budget.resume();
+        add_to_now(setter, TimeDelta::hours(1));
+    }
+
+    #[test]
+    fn refill_weekly_with_carry_over() {
+        for (carry_over, expected_left) in [
+            (CarryOver::None, "04:00:00"),
+            (CarryOver::Unused, "04:00:00"),
+            (CarryOver::All, "02:00:00"),
+        ] {
+            let (mut budget, mut setter) = support_duty(carry_over);
+            use_across_two_weeks(&mut budget, &mut setter);
+            let periods = budget.periods();
+            pretty_assertions::assert_eq!(2, periods.len());
+            pretty_assertions::assert_eq!(
+                new_utc_moment("2024-04-29 00:00:00"),
+                periods[0].start()
+            );
+            pretty_assertions::assert_eq!(new_utc_moment("2024-05-06 00:00:00"), periods[0].end());
+            pretty_assertions::assert_eq!("07:00:00", periods[0].used().to_string());
+            pretty_assertions::assert_eq!(
+                expected_left,
+                budget.left_in_period().to_string(),
+                "Carry over: {}",
+                carry_over
+            );
+        }
+
+        let (mut budget, mut setter) = support_duty(CarryOver::Unused);
+        add_to_now(&mut setter, TimeDelta::hours(2));
+        budget.pause();
+        setter.set_now(new_utc_moment("2024-05-07 09:00:00"));
+        pretty_assertions::assert_eq!("08:00:00", budget.left_in_period().to_string());
+    }
+
+    #[test]
+    fn skip_unused_periods_to_current_one() {
+        for (carry_over, refill, expected_available) in [
+            (CarryOver::None, RefillPeriod::Weekly, "05:00:00"),
+            (CarryOver::Unused, RefillPeriod::Weekly, "1d 00:00:00"),
+            (CarryOver::All, RefillPeriod::Weekly, "22:00:00"),
+            (CarryOver::All, RefillPeriod::Daily, "6d 18:00:00"),
+            (CarryOver::All, RefillPeriod::Monthly, "02:00:00"),
+        ] {
+            let (mut budget, mut setter) = support_duty(carry_over);
+            budget.refill = refill;
+            use_across_two_weeks(&mut budget, &mut setter);
+            budget.pause();
+            budget.close_due_periods();
+            setter.set_now(new_utc_moment("2024-06-03 12:00:00"));
+
+            let walked = budget.open_periods().pop();
+            pretty_assertions::assert_eq!(walked, budget.current_period());
+            pretty_assertions::assert_eq!(
+                expected_available,
+                walked.unwrap().available().to_string(),
+                "Carry over: {}, refill: {}",
+                carry_over,
+                refill
+            );
+        }
+    }
+
+    #[test]
+    fn split_usage_at_midnight() {
+        let time_impl = MockTimeImpl::new(new_utc_moment("2024-05-01 21:00:00"));
+        let mut setter = time_impl.clone();
+        let mut budget = Budget::new_with_impl(
+            time_impl,
+            ClockDuration::new_secs_mins_hours(Some(3), None, None),
+            RefillPeriod::Daily,
+            CarryOver::None,
+        );
+        budget
+            .mut_stopwatch()
+            .set_time_zone(Some(chrono_tz::Europe::Berlin));
+        add_to_now(&mut setter, TimeDelta::hours(3));
+
+        let used: Vec<String> = budget
+            .periods()
+            .iter()
+            .map(|period| period.used().to_string())
+            .collect();
+        pretty_assertions::assert_eq!(vec!["01:00:00", "02:00:00"], used);
+        pretty_assertions::assert_eq!(
+            new_utc_moment("2024-05-02 22:00:00"),
+            budget.current_period().unwrap().end()
+        );
+        pretty_assertions::assert_eq!("01:00:00", budget.left_in_period().to_string());
+    }
+
+    #[test]
+    fn keep_closed_periods_on_reset_and_zone_change() {
+        let time_impl = MockTimeImpl::new(new_utc_moment("2024-05-01 08:00:00"));
+        let mut setter = time_impl.clone();
+        let mut budget = Budget::new_with_impl(
+            time_impl,
+            ClockDuration::new_secs_mins_hours(Some(3), None, None),
+            RefillPeriod::Daily,
+            CarryOver::Unused,
+        );
+        budget.mut_stopwatch().set_time_zone(Some(chrono_tz::UTC));
+        add_to_now(&mut setter, TimeDelta::hours(2));
+        budget.pause();
+        setter.set_now(new_utc_moment("2024-05-02 09:00:00"));
+        budget.resume();
+        add_to_now(&mut setter, TimeDelta::hours(1));
+        budget.close_due_periods();
+        let first_day = budget.periods()[0];
+
+        budget
+            .mut_stopwatch()
+            .set_time_zone(Some(chrono_tz::Asia::Tokyo));
+        budget.reset();
+        add_to_now(&mut setter, TimeDelta::hours(1));
+
+        let periods: Vec<(UtcDateTime, UtcDateTime, String, String, String)> = budget
+            .periods()
+            .iter()
+            .map(|period| {
+                (
+                    period.start(),
+                    period.end(),
+                    period.available().to_string(),
+                    period.used().to_string(),
+                    period.carried().to_string(),
+                )
+            })
+            .collect();
+        pretty_assertions::assert_eq!(first_day, budget.periods()[0]);
+        pretty_assertions::assert_eq!(
+            vec![
+                (
+                    new_utc_moment("2024-05-01 00:00:00"),
+                    new_utc_moment("2024-05-02 00:00:00"),
+                    "03:00:00".to_string(),
+                    "02:00:00".to_string(),
+                    "00:00:00".to_string()
+                ),
+                (
+                    new_utc_moment("2024-05-02 00:00:00"),
+                    new_utc_moment("2024-05-02 10:00:00"),
+                    "04:00:00".to_string(),
+                    "01:00:00".to_string(),
+                    "01:00:00".to_string()
+                ),
+                // Midnight in Tokyo
+                (
+                    new_utc_moment("2024-05-02 10:00:00"),
+                    new_utc_moment("2024-05-02 15:00:00"),
+                    "03:00:00".to_string(),
+                    "01:00:00".to_string(),
+                    "00:00:00".to_string()
+                ),
+            ],
+            periods
+        );
+    }
+}
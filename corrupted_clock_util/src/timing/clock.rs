@@ -0,0 +1,334 @@
+use super::{
+    Adjustment, Budget, CarryOver, ClockDuration, ClockState, ClockType, CountDown,
+    InvalidSegmentEdit, Lap, Pomodoro, PomodoroConfig, RefillPeriod, SegmentEdit, Sequence, Stage,
+    Stopwatch, TimeImpl, TimeSegment, Timer, UtcDateTime, UtcTimeImpl,
+};
+
+/// Any kind of clock kept in a clock table.
+///
+/// A new kind of clock needs a variant here, an arm in the macro `on_clock`
+/// and an entry in the macro `clock_kinds`.
+#[derive(Debug)]
+pub enum Clock<T = UtcTimeImpl>
+where
+    T: Default,
+{
+    Stopwatch(Stopwatch<T>),
+    CountDown(CountDown<T>),
+    Pomodoro(Pomodoro<T>),
+    Sequence(Sequence<T>),
+    Budget(Budget<T>),
+}
+
+/// Evaluates the expression with the clock inside of any variant
+macro_rules! on_clock {
+    ($clock:expr, $inner:ident => $body:expr) => {
+        match $clock {
+            Clock::Stopwatch($inner) => $body,
+            Clock::CountDown($inner) => $body,
+            Clock::Pomodoro($inner) => $body,
+            Clock::Sequence($inner) => $body,
+            Clock::Budget($inner) => $body,
+        }
+    };
+}
+
+/// Implements for every kind of clock its clock type, access to it as this kind
+/// and the conversion from it into a clock
+macro_rules! clock_kinds {
+    ($($kind:ident => $as_kind:ident, $as_mut_kind:ident;)*) => {
+        impl<T> Clock<T>
+        where
+            T: Default,
+        {
+            pub fn clock_type(&self) -> ClockType {
+                match self {
+                    $(Clock::$kind(_) => ClockType::$kind,)*
+                }
+            }
+
+            $(
+                pub fn $as_kind(&self) -> Option<&$kind<T>> {
+                    match self {
+                        Clock::$kind(clock) => Some(clock),
+                        _ => None,
+                    }
+                }
+
+                pub fn $as_mut_kind(&mut self) -> Option<&mut $kind<T>> {
+                    match self {
+                        Clock::$kind(clock) => Some(clock),
+                        _ => None,
+                    }
+                }
+            )*
+        }
+
+        $(
+            impl<T: Default> From<$kind<T>> for Clock<T> {
+                fn from(value: $kind<T>) -> Self {
+                    Clock::$kind(value)
+                }
+            }
+        )*
+    };
+}
+
+clock_kinds! {
+    Stopwatch => as_stopwatch, as_mut_stopwatch;
+    CountDown => as_count_down, as_mut_count_down;
+    Pomodoro => as_pomodoro, as_mut_pomodoro;
+    Sequence => as_sequence, as_mut_sequence;
+    Budget => as_budget, as_mut_budget;
+}
+
+/// Constructors per kind of clock which start now or at the given moment in the future
+impl Clock<UtcTimeImpl> {
+    pub fn new_stopwatch(start: Option<UtcDateTime>) -> Self {
+        match start {
+            Some(start) => Stopwatch::new_scheduled(start),
+            None => Stopwatch::new(),
+        }
+        .into()
+    }
+
+    pub fn new_count_down(time: ClockDuration, start: Option<UtcDateTime>) -> Self {
+        match start {
+            Some(start) => CountDown::new_scheduled(time, start),
+            None => CountDown::new(time),
+        }
+        .into()
+    }
+
+    pub fn new_pomodoro(config: PomodoroConfig, start: Option<UtcDateTime>) -> Self {
+        match start {
+            Some(start) => Pomodoro::new_scheduled(config, start),
+            None => Pomodoro::new(config),
+        }
+        .into()
+    }
+
+    pub fn new_sequence(stages: Vec<Stage>, start: Option<UtcDateTime>) -> Self {
+        match start {
+            Some(start) => Sequence::new_scheduled(stages, start),
+            None => Sequence::new(stages),
+        }
+        .into()
+    }
+
+    pub fn new_budget(
+        allowance: ClockDuration,
+        refill: RefillPeriod,
+        carry_over: CarryOver,
+        start: Option<UtcDateTime>,
+    ) -> Self {
+        match start {
+            Some(start) => Budget::new_scheduled(allowance, refill, carry_over, start),
+            None => Budget::new(allowance, refill, carry_over),
+        }
+        .into()
+    }
+}
+
+impl<T> Clock<T>
+where
+    T: Default,
+{
+    /// Stopwatch which tracks the running time of this clock
+    pub fn stopwatch(&self) -> &Stopwatch<T> {
+        on_clock!(self, clock => clock.stopwatch())
+    }
+
+    /// Settings shared by every kind of clock, like group, time zone and speed, are changed here.
+    /// Refresh a budget before changing its time zone, so its due periods are closed in the old one.
+    pub fn mut_stopwatch(&mut self) -> &mut Stopwatch<T> {
+        on_clock!(self, clock => clock.mut_stopwatch())
+    }
+}
+
+impl<T> Clock<T>
+where
+    T: TimeImpl + Default,
+{
+    pub fn laps(&self) -> &[Lap] {
+        on_clock!(self, clock => clock.laps())
+    }
+
+    pub fn lap(&mut self) -> Lap {
+        on_clock!(self, clock => clock.lap())
+    }
+
+    pub fn edit(&mut self, edit: SegmentEdit) -> Result<(), InvalidSegmentEdit> {
+        on_clock!(self, clock => clock.edit(edit))
+    }
+
+    /// Resets the clock but keeps the run so far in the run history
+    pub fn reset_archived(&mut self) {
+        let (passed, finished_at) = (self.passed(), self.finished_at());
+        self.mut_stopwatch().archive_run(passed, finished_at);
+        self.reset();
+    }
+
+    /// Brings records, which depend on the current time, up to date before saving.
+    pub fn refresh(&mut self) {
+        self.mut_stopwatch().record_evaluation();
+        match self {
+            Clock::Stopwatch(stopwatch) => stopwatch.apply_cap(),
+            Clock::Pomodoro(pomodoro) => pomodoro.record_finished_phases(),
+            Clock::Budget(budget) => budget.close_due_periods(),
+            Clock::CountDown(_) | Clock::Sequence(_) => (),
+        }
+    }
+}
+
+impl<T> Timer for Clock<T>
+where
+    T: TimeImpl + Default,
+{
+    fn created_at(&self) -> UtcDateTime {
+        on_clock!(self, clock => clock.created_at())
+    }
+
+    fn start_moment(&self) -> UtcDateTime {
+        on_clock!(self, clock => clock.start_moment())
+    }
+
+    fn last_resumed_at(&self) -> Option<UtcDateTime> {
+        on_clock!(self, clock => clock.last_resumed_at())
+    }
+
+    fn last_paused_at(&self) -> Option<UtcDateTime> {
+        on_clock!(self, clock => clock.last_paused_at())
+    }
+
+    fn passed(&self) -> ClockDuration {
+        on_clock!(self, clock => clock.passed())
+    }
+
+    fn paused_time(&self) -> ClockDuration {
+        on_clock!(self, clock => clock.paused_time())
+    }
+
+    fn is_paused(&self) -> bool {
+        on_clock!(self, clock => clock.is_paused())
+    }
+
+    fn pause(&mut self) {
+        on_clock!(self, clock => clock.pause())
+    }
+
+    fn resume(&mut self) {
+        on_clock!(self, clock => clock.resume())
+    }
+
+    fn reset(&mut self) {
+        on_clock!(self, clock => clock.reset())
+    }
+
+    fn running_segments(&self) -> Vec<TimeSegment> {
+        on_clock!(self, clock => clock.running_segments())
+    }
+
+    fn adjust(&mut self, amount: ClockDuration, reason: Option<String>) {
+        on_clock!(self, clock => clock.adjust(amount, reason))
+    }
+
+    fn adjustments(&self) -> &[Adjustment] {
+        on_clock!(self, clock => clock.adjustments())
+    }
+
+    fn starts_in(&self) -> Option<ClockDuration> {
+        on_clock!(self, clock => clock.starts_in())
+    }
+
+    fn state(&self) -> ClockState {
+        on_clock!(self, clock => clock.state())
+    }
+
+    fn finished_at(&self) -> Option<UtcDateTime> {
+        on_clock!(self, clock => clock.finished_at())
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use chrono::TimeDelta;
+
+    use crate::timing::{
+        mocking_time::MockTimeImpl,
+        test_utils::{add_to_now, new_utc_moment},
+        Stage,
+    };
+
+    use super::*;
+
+    fn minutes(minutes: i64) -> ClockDuration {
+        ClockDuration::new_secs_mins_hours(None, Some(minutes), None)
+    }
+
+    fn runs_of(clock: &Clock<MockTimeImpl>) -> Vec<(UtcDateTime, UtcDateTime, String, String)> {
+        clock
+            .stopwatch()
+            .runs()
+            .iter()
+            .map(|run| {
+                (
+                    run.started_at(),
+                    run.ended_at(),
+                    run.passed().to_string(),
+                    run.paused().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reset_archived_finished_count_down() {
+        let time_impl = MockTimeImpl::new(new_utc_moment("2024-05-01 08:00:00"));
+        let mut setter = time_impl.clone();
+        let mut clock: Clock<MockTimeImpl> =
+            CountDown::new_with_impl(time_impl, minutes(10)).into();
+
+        add_to_now(&mut setter, TimeDelta::minutes(3));
+        clock.pause();
+        add_to_now(&mut setter, TimeDelta::minutes(2));
+        clock.resume();
+        add_to_now(&mut setter, TimeDelta::days(1));
+        clock.reset_archived();
+
+        pretty_assertions::assert_eq!(
+            vec![(
+                new_utc_moment("2024-05-01 08:00:00"),
+                new_utc_moment("2024-05-01 08:12:00"),
+                "00:10:00".to_string(),
+                "00:02:00".to_string(),
+            )],
+            runs_of(&clock)
+        );
+    }
+
+    #[test]
+    fn reset_archived_finished_sequence() {
+        let time_impl = MockTimeImpl::new(new_utc_moment("2024-05-01 08:00:00"));
+        let mut setter = time_impl.clone();
+        let stages = [("prep", 2), ("run", 20)]
+            .map(|(label, length)| Stage::new(label.to_string(), minutes(length)))
+            .into();
+        let mut clock: Clock<MockTimeImpl> = Sequence::new_with_impl(time_impl, stages).into();
+
+        add_to_now(&mut setter, TimeDelta::minutes(30));
+        clock.pause();
+        add_to_now(&mut setter, TimeDelta::days(1));
+        clock.reset_archived();
+
+        pretty_assertions::assert_eq!(
+            vec![(
+                new_utc_moment("2024-05-01 08:00:00"),
+                new_utc_moment("2024-05-01 08:22:00"),
+                "00:22:00".to_string(),
+                "00:00:00".to_string(),
+            )],
+            runs_of(&clock)
+        );
+    }
+}
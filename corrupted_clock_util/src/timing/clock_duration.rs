@@ -1,12 +1,20 @@
+mod duration_style;
 mod from_str;
+mod precision;
 use chrono::TimeDelta;
+pub use duration_style::{DurationFormat, DurationStyle, StyledDuration};
+pub use from_str::InvalidClockDurationString;
+pub use precision::Precision;
 use serde::{Deserialize, Serialize};
 
 use super::ChronoDuration;
 
-const SECONDS_RATE: i64 = 60;
-const MINUTES_RATE: i64 = SECONDS_RATE * SECONDS_RATE;
-const HOURS_RATE: i64 = MINUTES_RATE * 24;
+const SECS_PER_MINUTE: i64 = 60;
+const SECS_PER_HOUR: i64 = SECS_PER_MINUTE * 60;
+const HOURS_PER_DAY: i64 = 24;
+const SECS_PER_DAY: i64 = SECS_PER_HOUR * HOURS_PER_DAY;
+const DAYS_PER_WEEK: i64 = 7;
+const NANOS_RATE: i128 = 1_000_000_000;
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct ClockDuration {
@@ -36,6 +44,33 @@ impl std::ops::Sub for ClockDuration {
         delta.into()
     }
 }
+impl std::ops::Mul<u32> for ClockDuration {
+    type Output = ClockDuration;
+
+    fn mul(self, rhs: u32) -> Self::Output {
+        Self::from_total_nanos(self.total_nanos() * i128::from(rhs))
+    }
+}
+
+impl std::ops::Rem for ClockDuration {
+    type Output = ClockDuration;
+
+    /// # Panics
+    ///
+    /// If `rhs` is zero
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self::from_total_nanos(self.total_nanos() % rhs.total_nanos())
+    }
+}
+
+impl std::ops::Neg for ClockDuration {
+    type Output = ClockDuration;
+
+    fn neg(self) -> Self::Output {
+        (-ChronoDuration::from(self)).into()
+    }
+}
+
 impl std::ops::Add for ClockDuration {
     type Output = ClockDuration;
 
@@ -54,32 +89,76 @@ impl ClockDuration {
         self.nanos
     }
 
+    pub fn is_zero(&self) -> bool {
+        self.secs == 0 && self.nanos == 0
+    }
+
+    /// How many times `rhs` fits completely into this duration
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is zero
+    pub fn div_duration(&self, rhs: Self) -> i64 {
+        (self.total_nanos() / rhs.total_nanos()) as i64
+    }
+
+    pub(crate) fn total_nanos(&self) -> i128 {
+        i128::from(self.secs) * NANOS_RATE + i128::from(self.nanos)
+    }
+
+    pub(crate) fn from_total_nanos(nanos: i128) -> Self {
+        Self {
+            secs: (nanos / NANOS_RATE) as i64,
+            nanos: (nanos % NANOS_RATE) as i32,
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.secs < 0 || self.nanos < 0
+    }
+
+    pub fn abs(&self) -> Self {
+        if self.is_negative() {
+            -*self
+        } else {
+            *self
+        }
+    }
+
     pub fn secs(&self) -> i32 {
-        (self.secs % SECONDS_RATE) as i32
+        (self.secs % SECS_PER_MINUTE) as i32
     }
     pub fn mins(&self) -> i32 {
-        ((self.secs / SECONDS_RATE) % SECONDS_RATE) as i32
+        ((self.secs / SECS_PER_MINUTE) % 60) as i32
     }
+    /// Hours within the last started day
     pub fn hours(&self) -> i32 {
-        ((self.secs / MINUTES_RATE) % HOURS_RATE) as i32
+        ((self.secs / SECS_PER_HOUR) % HOURS_PER_DAY) as i32
+    }
+    /// Days within the last started week
+    pub fn days(&self) -> i32 {
+        (self.total_days() % DAYS_PER_WEEK) as i32
+    }
+    pub fn weeks(&self) -> i64 {
+        self.total_days() / DAYS_PER_WEEK
+    }
+    pub fn total_hours(&self) -> i64 {
+        self.secs / SECS_PER_HOUR
+    }
+    pub fn total_days(&self) -> i64 {
+        self.secs / SECS_PER_DAY
     }
 }
 
 impl std::fmt::Display for ClockDuration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{:02}:{:02}:{:02}",
-            self.hours(),
-            self.mins(),
-            self.secs()
-        )
+        self.display(DurationStyle::default()).fmt(f)
     }
 }
 
 impl From<ClockDuration> for ChronoDuration {
     fn from(value: ClockDuration) -> Self {
-        ChronoDuration::new(value.secs, value.nanos as u32).unwrap()
+        ChronoDuration::seconds(value.secs) + ChronoDuration::nanoseconds(value.nanos.into())
     }
 }
 
@@ -89,3 +168,72 @@ impl From<ChronoDuration> for ClockDuration {
         Self { secs, nanos }
     }
 }
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn display_signed_durations() {
+        let duration = ClockDuration::new_secs_mins_hours(Some(1), Some(2), Some(3));
+        assert_eq!("01:02:03", duration.to_string());
+        assert_eq!("-01:02:03", (-duration).to_string());
+        assert_eq!("00:00:00", ClockDuration::default().to_string());
+    }
+
+    #[test]
+    fn display_in_styles() {
+        let duration = ClockDuration::new_secs_mins_hours(Some(28), Some(5), Some(6));
+        let week_and_more = ClockDuration::new_secs_mins_hours(Some(24 * 9 + 1), None, None);
+        let actual: Vec<String> = [
+            DurationStyle::Days,
+            DurationStyle::Weeks,
+            DurationStyle::TotalHours,
+            DurationStyle::Short,
+            DurationStyle::DecimalHours,
+        ]
+        .into_iter()
+        .flat_map(|style| {
+            [duration, -duration, week_and_more, ClockDuration::default()]
+                .map(|to_show| format!("{}: {}", style, to_show.display(style)))
+        })
+        .collect();
+        insta::assert_debug_snapshot!(actual);
+    }
+
+    #[test]
+    fn display_with_precision() {
+        let duration = ClockDuration::from(TimeDelta::microseconds(4_319_996_500));
+        let actual: Vec<String> = [
+            Precision::Seconds,
+            Precision::Deciseconds,
+            Precision::Milliseconds,
+        ]
+        .into_iter()
+        .flat_map(|precision| {
+            [
+                DurationFormat::new(DurationStyle::Days, precision),
+                DurationFormat::new(DurationStyle::Short, precision),
+            ]
+        })
+        .flat_map(|format| {
+            [
+                duration,
+                -duration,
+                ClockDuration::from(TimeDelta::microseconds(-400)),
+            ]
+            .map(|to_show| to_show.display(format).to_string())
+        })
+        .collect();
+        insta::assert_debug_snapshot!(actual);
+    }
+
+    #[test]
+    fn multiply_and_divide() {
+        let minute = ClockDuration::new_secs_mins_hours(None, Some(1), None);
+        let ninety_secs = ClockDuration::new_secs_mins_hours(None, None, Some(90));
+        assert_eq!("00:03:00", (minute * 3).to_string());
+        assert_eq!(1, ninety_secs.div_duration(minute));
+        assert_eq!("00:00:30", (ninety_secs % minute).to_string());
+    }
+}
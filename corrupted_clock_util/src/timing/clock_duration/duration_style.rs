@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ClockDuration, Precision, NANOS_RATE};
+
+const NANOS_PER_HOUR: f64 = (NANOS_RATE * 3600) as f64;
+
+/// How a duration is written out as text
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DurationStyle {
+    /// Whole days in front of the time of the day, like "1d 04:05:06"
+    #[default]
+    Days,
+    /// Whole weeks and days in front of the time of the day, like "1w 2d 04:05:06"
+    Weeks,
+    /// All hours in one field, like "28:05:06"
+    TotalHours,
+    /// Only the units which are not zero, like "1h 5m"
+    Short,
+    /// Hours with 2 decimal places, like "28.08h"
+    DecimalHours,
+}
+
+impl std::fmt::Display for DurationStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DurationStyle::Days => f.write_str("days"),
+            DurationStyle::Weeks => f.write_str("weeks"),
+            DurationStyle::TotalHours => f.write_str("total hours"),
+            DurationStyle::Short => f.write_str("short"),
+            DurationStyle::DecimalHours => f.write_str("decimal hours"),
+        }
+    }
+}
+
+/// Style and precision in which a duration is written out
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DurationFormat {
+    style: DurationStyle,
+    precision: Precision,
+}
+
+impl DurationFormat {
+    pub fn new(style: DurationStyle, precision: Precision) -> Self {
+        Self { style, precision }
+    }
+
+    pub fn style(&self) -> DurationStyle {
+        self.style
+    }
+
+    pub fn precision(&self) -> Precision {
+        self.precision
+    }
+}
+
+impl From<DurationStyle> for DurationFormat {
+    fn from(style: DurationStyle) -> Self {
+        Self::new(style, Precision::default())
+    }
+}
+
+impl From<Precision> for DurationFormat {
+    fn from(precision: Precision) -> Self {
+        Self::new(DurationStyle::default(), precision)
+    }
+}
+
+/// Duration which is displayed in a given format.
+/// Created via [`ClockDuration::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct StyledDuration {
+    duration: ClockDuration,
+    format: DurationFormat,
+}
+
+impl ClockDuration {
+    pub fn display(&self, format: impl Into<DurationFormat>) -> StyledDuration {
+        StyledDuration {
+            duration: *self,
+            format: format.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for StyledDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = self.format.precision();
+        // Rounding happens before the sign is decided so a tiny negative duration
+        // is not shown as a negative zero.
+        let rounded = self.duration.round(precision);
+        let (sign, positive) = if rounded.is_negative() {
+            ("-", rounded.abs())
+        } else {
+            ("", rounded)
+        };
+        f.write_str(sign)?;
+        let fraction = match precision.decimal_places() {
+            0 => String::new(),
+            places => format!(".{:0places$}", positive.fraction(precision)),
+        };
+        let time_of_day = format!(
+            "{:02}:{:02}:{:02}{}",
+            positive.hours(),
+            positive.mins(),
+            positive.secs(),
+            fraction
+        );
+        match self.format.style() {
+            DurationStyle::Days => match positive.total_days() {
+                0 => f.write_str(&time_of_day),
+                days => write!(f, "{}d {}", days, time_of_day),
+            },
+            DurationStyle::Weeks => {
+                for (value, unit) in [(positive.weeks(), "w"), (positive.days().into(), "d")] {
+                    if value != 0 {
+                        write!(f, "{}{} ", value, unit)?;
+                    }
+                }
+                f.write_str(&time_of_day)
+            }
+            DurationStyle::TotalHours => write!(
+                f,
+                "{:02}:{:02}:{:02}{}",
+                positive.total_hours(),
+                positive.mins(),
+                positive.secs(),
+                fraction
+            ),
+            DurationStyle::Short => {
+                let mut units: Vec<String> = [
+                    (positive.total_days(), "d"),
+                    (positive.hours().into(), "h"),
+                    (positive.mins().into(), "m"),
+                ]
+                .into_iter()
+                .filter(|(value, _)| *value != 0)
+                .map(|(value, unit)| format!("{}{}", value, unit))
+                .collect();
+                let secs = positive.secs();
+                if secs != 0 || positive.fraction(precision) != 0 || units.is_empty() {
+                    units.push(format!("{}{}s", secs, fraction));
+                }
+                f.write_str(&units.join(" "))
+            }
+            DurationStyle::DecimalHours => {
+                write!(f, "{:.2}h", positive.total_nanos() as f64 / NANOS_PER_HOUR)
+            }
+        }
+    }
+}
@@ -22,11 +22,23 @@ pub enum InvalidClockDurationString {
     NegativeSeconds,
 }
 
+impl ClockDuration {
+    /// Parses a duration with an optional leading sign. A leading "-" yields a negative duration.
+    ///
+    /// Syntax: [+|-]<duration>
+    pub fn parse_signed(s: &str) -> Result<Self, InvalidClockDurationString> {
+        match s.strip_prefix('-') {
+            Some(positive) => positive.parse().map(|duration: ClockDuration| -duration),
+            None => s.strip_prefix('+').unwrap_or(s).parse(),
+        }
+    }
+}
+
 impl FromStr for ClockDuration {
     type Err = InvalidClockDurationString;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        fn group_to_number<'h>(matched: Option<&str>) -> Option<Result<i64, ParseIntError>> {
+        fn group_to_number(matched: Option<&str>) -> Option<Result<i64, ParseIntError>> {
             matched.map(|element| element.parse())
         }
 
@@ -143,4 +155,18 @@ mod testing {
         );
         assert_case("-1:00:00", Err(InvalidClockDurationString::NegativeHours));
     }
+
+    #[test]
+    fn parse_signed_duration() {
+        fn assert_case(input: &str, expected: Result<ClockDuration, InvalidClockDurationString>) {
+            let actual = ClockDuration::parse_signed(input);
+            assert_eq!(expected, actual, "Actul input: '{}'", input);
+        }
+        let fifteen_minutes = ClockDuration::new_secs_mins_hours(None, Some(15), None);
+        assert_case("15:00", Ok(fifteen_minutes));
+        assert_case("+15:00", Ok(fifteen_minutes));
+        assert_case("-15:00", Ok(-fifteen_minutes));
+        assert_case("--15:00", Err(InvalidClockDurationString::NegativeMinutes));
+        assert_case("-aa", Err(InvalidClockDurationString::InvalidSeconds));
+    }
 }
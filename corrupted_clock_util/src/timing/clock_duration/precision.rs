@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ClockDuration, NANOS_RATE};
+
+/// Smallest unit of a duration which is shown
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    #[default]
+    Seconds,
+    Deciseconds,
+    Milliseconds,
+    Microseconds,
+}
+
+impl Precision {
+    /// How many digits are shown after the seconds
+    pub fn decimal_places(&self) -> usize {
+        match self {
+            Precision::Seconds => 0,
+            Precision::Deciseconds => 1,
+            Precision::Milliseconds => 3,
+            Precision::Microseconds => 6,
+        }
+    }
+
+    fn nanos_per_unit(&self) -> i128 {
+        NANOS_RATE / 10_i128.pow(self.decimal_places() as u32)
+    }
+}
+
+impl ClockDuration {
+    /// Rounds to the nearest multiple of the unit of the given precision.
+    /// Halfway values are rounded away from zero.
+    pub fn round(&self, precision: Precision) -> Self {
+        let unit = precision.nanos_per_unit();
+        let total = self.total_nanos();
+        let half = if total < 0 { -unit / 2 } else { unit / 2 };
+        Self::from_total_nanos((total + half) / unit * unit)
+    }
+
+    /// Fraction of the last started second in units of the given precision
+    pub fn fraction(&self, precision: Precision) -> i32 {
+        self.nanos / precision.nanos_per_unit() as i32
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use chrono::TimeDelta;
+
+    use super::*;
+
+    #[test]
+    fn round_to_precision() {
+        let duration = ClockDuration::from(TimeDelta::microseconds(4_319_996_500));
+        let actual: Vec<String> = [
+            Precision::Seconds,
+            Precision::Deciseconds,
+            Precision::Milliseconds,
+            Precision::Microseconds,
+        ]
+        .into_iter()
+        .flat_map(|precision| {
+            [duration, -duration]
+                .map(|to_round| format!("{:?}", to_round.round(precision).total_nanos()))
+        })
+        .collect();
+        insta::assert_debug_snapshot!(actual);
+    }
+}
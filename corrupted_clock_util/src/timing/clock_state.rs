@@ -0,0 +1,22 @@
+/// Lifecycle of a clock.
+/// The order is the one used for sorting clocks by their state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClockState {
+    Running,
+    Paused,
+    /// The start of the clock lies in the future
+    Scheduled,
+    /// A count down reached zero
+    Finished,
+}
+
+impl std::fmt::Display for ClockState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClockState::Running => f.write_str("running"),
+            ClockState::Paused => f.write_str("paused"),
+            ClockState::Scheduled => f.write_str("scheduled"),
+            ClockState::Finished => f.write_str("finished"),
+        }
+    }
+}
@@ -1,17 +1,39 @@
-use std::collections::HashMap;
+mod persisted_state;
 
-use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize, Serializer};
 use thiserror::Error;
 
-use super::{CountDown, Stopwatch, UtcTimeImpl};
+use self::persisted_state::{PersistedClockTable, PersistedClockTableRef};
+
+use super::{
+    Clock, ClockState, ClockType, CountDown, ExclusiveMode, Settings, Stopwatch, TimeImpl,
+    TimeJump, TimeJumpPolicy, Timer, UtcTimeImpl,
+};
 
-#[derive(Serialize, Deserialize, Default, Debug)]
+/// All clocks of the app with their settings.
+/// A clock is identified by its kind and its name.
+#[derive(Deserialize, Default, Debug)]
+#[serde(from = "PersistedClockTable<T>")]
 pub struct ClockTable<T = UtcTimeImpl>
 where
     T: Default,
 {
-    stopwatches: HashMap<String, Stopwatch<T>>,
-    count_downs: HashMap<String, CountDown<T>>,
+    clocks: BTreeMap<(ClockType, String), Clock<T>>,
+    settings: Settings,
+}
+
+impl<T> Serialize for ClockTable<T>
+where
+    T: Default + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        PersistedClockTableRef::from(self).serialize(serializer)
+    }
 }
 
 #[derive(Debug, Error)]
@@ -26,93 +48,274 @@ where
         stopwatches: HashMap<String, Stopwatch<T>>,
         count_downs: HashMap<String, CountDown<T>>,
     ) -> Self {
+        let stopwatches = stopwatches
+            .into_iter()
+            .map(|(name, clock)| ((ClockType::Stopwatch, name), clock.into()));
+        let count_downs = count_downs
+            .into_iter()
+            .map(|(name, clock)| ((ClockType::CountDown, name), clock.into()));
         Self {
-            stopwatches,
-            count_downs,
+            clocks: stopwatches.chain(count_downs).collect(),
+            settings: Default::default(),
         }
     }
 
-    pub fn all_stopwatches(&self) -> impl Iterator<Item = (&str, &Stopwatch<T>)> {
-        self.stopwatches
-            .iter()
-            .map(|(key, value)| (key.as_str(), value))
+    pub fn settings(&self) -> &Settings {
+        &self.settings
     }
 
-    pub fn all_count_downs(&self) -> impl Iterator<Item = (&str, &CountDown<T>)> {
-        self.count_downs
+    pub fn mut_settings(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    pub fn set_time_jump_policy(&mut self, policy: TimeJumpPolicy) {
+        self.settings.set_time_jump_policy(policy);
+        for clock in self.clocks.values_mut() {
+            clock.mut_stopwatch().set_jump_policy(policy);
+        }
+    }
+
+    /// Clocks of the given kind or of all kinds if none is given, ordered by kind and name
+    pub fn all(&self, clock_type: Option<ClockType>) -> impl Iterator<Item = (&str, &Clock<T>)> {
+        self.clocks
             .iter()
-            .map(|(key, value)| (key.as_str(), value))
+            .filter(move |((other_type, _), _)| clock_type.is_none_or(|given| given == *other_type))
+            .map(|((_, name), clock)| (name.as_str(), clock))
     }
 
-    pub fn mut_all_count_downs(&mut self) -> impl Iterator<Item = &mut CountDown<T>> {
-        self.count_downs.values_mut().into_iter()
+    /// Clocks of the given kind or of all kinds if none is given
+    pub fn mut_all(
+        &mut self,
+        clock_type: Option<ClockType>,
+    ) -> impl Iterator<Item = &mut Clock<T>> {
+        self.clocks
+            .iter_mut()
+            .filter(move |((other_type, _), _)| clock_type.is_none_or(|given| given == *other_type))
+            .map(|(_, clock)| clock)
     }
 
-    pub fn mut_all_stop_watches(&mut self) -> impl Iterator<Item = &mut Stopwatch<T>> {
-        self.stopwatches.values_mut().into_iter()
+    pub fn get(&self, clock_type: ClockType, key: &str) -> Option<&Clock<T>> {
+        self.clocks.get(&(clock_type, key.to_owned()))
     }
 
-    pub fn remove_count_down(&mut self, key: &str) -> bool {
-        self.count_downs.remove(key).is_some()
+    pub fn get_mut(&mut self, clock_type: ClockType, key: &str) -> Option<&mut Clock<T>> {
+        self.clocks.get_mut(&(clock_type, key.to_owned()))
     }
 
-    pub fn remove_stopwatch(&mut self, key: &str) -> bool {
-        self.stopwatches.remove(key).is_some()
+    pub fn has(&self, clock_type: ClockType, key: &str) -> bool {
+        self.get(clock_type, key).is_some()
     }
 
-    pub fn remove_all_stopwatches(&mut self) {
-        self.stopwatches = Default::default();
+    pub fn add(&mut self, key: String, clock: impl Into<Clock<T>>) -> Result<(), AlreadyAdded> {
+        let mut clock = clock.into();
+        clock
+            .mut_stopwatch()
+            .set_jump_policy(self.settings.time_jump_policy());
+        let id = (clock.clock_type(), key);
+        if self.clocks.contains_key(&id) {
+            return Err(AlreadyAdded(id.1));
+        }
+        _ = self.clocks.insert(id, clock);
+        Ok(())
     }
 
-    pub fn remove_all_count_donws(&mut self) {
-        self.count_downs = Default::default();
+    pub fn remove(&mut self, clock_type: ClockType, key: &str) -> bool {
+        self.clocks.remove(&(clock_type, key.to_owned())).is_some()
     }
 
-    pub fn has_stop_watch(&self, key: &str) -> bool {
-        self.stopwatches.contains_key(key)
+    /// Removes the clocks of the given kind or of all kinds if none is given
+    pub fn remove_all(&mut self, clock_type: Option<ClockType>) {
+        self.clocks
+            .retain(|(other_type, _), _| clock_type.is_some_and(|given| given != *other_type));
     }
 
-    pub fn has_count_down(&self, key: &str) -> bool {
-        self.count_downs.contains_key(key)
+    pub fn all_stopwatches(&self) -> impl Iterator<Item = (&str, &Stopwatch<T>)> {
+        self.all(Some(ClockType::Stopwatch))
+            .filter_map(|(name, clock)| clock.as_stopwatch().map(|stopwatch| (name, stopwatch)))
     }
 
-    pub fn modify_stopwatch(&mut self, key: &str) -> Option<&mut Stopwatch<T>> {
-        self.stopwatches.get_mut(key)
+    pub fn all_count_downs(&self) -> impl Iterator<Item = (&str, &CountDown<T>)> {
+        self.all(Some(ClockType::CountDown))
+            .filter_map(|(name, clock)| clock.as_count_down().map(|count_down| (name, count_down)))
     }
 
-    pub fn modify_count_down(&mut self, key: &str) -> Option<&mut CountDown<T>> {
-        self.count_downs.get_mut(key)
+    pub fn mut_all_count_downs(&mut self) -> impl Iterator<Item = &mut CountDown<T>> {
+        self.mut_all(Some(ClockType::CountDown))
+            .filter_map(Clock::as_mut_count_down)
     }
 
     pub fn mut_stopwatch(&mut self, key: &str) -> Option<&mut Stopwatch<T>> {
-        self.stopwatches.get_mut(key)
+        self.get_mut(ClockType::Stopwatch, key)
+            .and_then(Clock::as_mut_stopwatch)
     }
 
     pub fn mut_count_down(&mut self, key: &str) -> Option<&mut CountDown<T>> {
-        self.count_downs.get_mut(key)
+        self.get_mut(ClockType::CountDown, key)
+            .and_then(Clock::as_mut_count_down)
     }
+}
 
-    pub fn get_stopwatch(&self, key: &str) -> Option<&Stopwatch<T>> {
-        self.stopwatches.get(key)
+impl<T> ClockTable<T>
+where
+    T: TimeImpl + Default,
+{
+    /// Clocks for which the system time lies before their last recorded moment
+    pub fn time_jumps(&self) -> Vec<(ClockType, &str, TimeJump)> {
+        self.clocks
+            .iter()
+            .filter_map(|((clock_type, name), clock)| {
+                clock
+                    .stopwatch()
+                    .time_jump()
+                    .map(|jump| (*clock_type, name.as_str(), jump))
+            })
+            .collect()
+    }
+
+    /// Brings records, which depend on the current time, up to date before saving.
+    pub fn refresh(&mut self) {
+        for clock in self.clocks.values_mut() {
+            clock.refresh();
+        }
     }
 
-    pub fn get_count_down(&self, key: &str) -> Option<&CountDown<T>> {
-        self.count_downs.get(key)
+    /// Pauses every other running clock in the scope of the given clock
+    /// according to the exclusive mode of this table.
+    /// Nothing is paused if the given clock is not running.
+    /// Returns the clocks which were paused.
+    pub fn pause_others(&mut self, clock_type: ClockType, name: &str) -> Vec<(ClockType, String)> {
+        self.pause_others_with(self.settings.exclusive_mode(), clock_type, name)
     }
 
-    pub fn add_stopwatch(&mut self, key: String, sw: Stopwatch<T>) -> Result<(), AlreadyAdded> {
-        if self.stopwatches.contains_key(&key) {
-            return Err(AlreadyAdded(key));
+    /// Same as [`Self::pause_others`] but with the given mode instead of the one of this table
+    pub fn pause_others_with(
+        &mut self,
+        mode: ExclusiveMode,
+        clock_type: ClockType,
+        name: &str,
+    ) -> Vec<(ClockType, String)> {
+        if mode == ExclusiveMode::Off {
+            return Vec::new();
         }
-        _ = self.stopwatches.insert(key, sw);
-        Ok(())
+        let Some(given_group) = self
+            .get(clock_type, name)
+            .filter(|clock| clock.state() == ClockState::Running)
+            .map(|clock| clock.stopwatch().group().map(ToOwned::to_owned))
+        else {
+            return Vec::new();
+        };
+        let group = match (mode, given_group) {
+            (ExclusiveMode::Group, None) => return Vec::new(),
+            (ExclusiveMode::Group, group) => group,
+            _ => None,
+        };
+        self.clocks
+            .iter_mut()
+            .filter(|((other_type, other_name), clock)| {
+                !(*other_type == clock_type && other_name == name)
+                    && clock.state() == ClockState::Running
+                    && group
+                        .as_deref()
+                        .is_none_or(|group| clock.stopwatch().group() == Some(group))
+            })
+            .map(|((other_type, other_name), clock)| {
+                clock.pause();
+                (*other_type, other_name.clone())
+            })
+            .collect()
     }
+}
+
+#[cfg(test)]
+mod testing {
+    use chrono::TimeDelta;
+
+    use crate::timing::{
+        mocking_time::MockTimeImpl,
+        test_utils::{add_to_now, new_utc_moment},
+        ClockDuration,
+    };
+
+    use super::*;
 
-    pub fn add_count_down(&mut self, key: String, sw: CountDown<T>) -> Result<(), AlreadyAdded> {
-        if self.count_downs.contains_key(&key) {
-            return Err(AlreadyAdded(key));
+    fn table_with_groups() -> (MockTimeImpl, ClockTable<MockTimeImpl>) {
+        let time_impl = MockTimeImpl::new(new_utc_moment("2024-05-01 08:00:00"));
+        let mut table = ClockTable::default();
+        for (name, group) in [
+            ("coding", Some("work")),
+            ("mails", Some("work")),
+            ("gym", None),
+        ] {
+            let mut stopwatch = Stopwatch::new_with_impl(time_impl.clone());
+            stopwatch.set_group(group.map(ToOwned::to_owned));
+            table.add(name.to_string(), stopwatch).unwrap();
         }
-        _ = self.count_downs.insert(key, sw);
-        Ok(())
+        let count_down = CountDown::new_with_impl(
+            time_impl.clone(),
+            ClockDuration::new_secs_mins_hours(Some(1), None, None),
+        );
+        table.add("tea".to_string(), count_down).unwrap();
+        (time_impl, table)
+    }
+
+    fn running(table: &ClockTable<MockTimeImpl>) -> Vec<&str> {
+        let mut running: Vec<&str> = table
+            .all(None)
+            .filter(|(_, clock)| clock.state() == ClockState::Running)
+            .map(|(name, _)| name)
+            .collect();
+        running.sort();
+        running
+    }
+
+    #[test]
+    fn pause_others_in_scope() {
+        let (mut setter, mut table) = table_with_groups();
+        add_to_now(&mut setter, TimeDelta::minutes(1));
+        let paused = table.pause_others(ClockType::Stopwatch, "coding");
+        pretty_assertions::assert_eq!(Vec::<(ClockType, String)>::new(), paused);
+
+        table
+            .mut_settings()
+            .set_exclusive_mode(ExclusiveMode::Group);
+        let paused = table.pause_others(ClockType::Stopwatch, "coding");
+        pretty_assertions::assert_eq!(vec![(ClockType::Stopwatch, "mails".to_string())], paused);
+        pretty_assertions::assert_eq!(vec!["coding", "gym", "tea"], running(&table));
+
+        let paused = table.pause_others(ClockType::Stopwatch, "gym");
+        pretty_assertions::assert_eq!(Vec::<(ClockType, String)>::new(), paused);
+
+        table
+            .mut_settings()
+            .set_exclusive_mode(ExclusiveMode::Global);
+        table.mut_count_down("tea").unwrap().pause();
+        let paused = table.pause_others(ClockType::CountDown, "tea");
+        pretty_assertions::assert_eq!(Vec::<(ClockType, String)>::new(), paused);
+        table.mut_count_down("tea").unwrap().resume();
+        table.pause_others(ClockType::CountDown, "tea");
+        pretty_assertions::assert_eq!(vec!["tea"], running(&table));
+    }
+
+    #[test]
+    fn load_and_save_state_file_with_maps_per_kind() {
+        let state_file = r#"{
+            "stopwatches": {
+                "coding": {
+                    "created_at": "2024-05-01T08:00:00Z",
+                    "start_moment": "2024-05-01T08:00:00Z",
+                    "segments": [{ "start": "2024-05-01T08:00:00Z", "end": "2024-05-01T09:00:00Z" }]
+                }
+            },
+            "count_downs": {}
+        }"#;
+        let table: ClockTable = serde_json::from_str(state_file).unwrap();
+        let coding = table.get(ClockType::Stopwatch, "coding").unwrap();
+        pretty_assertions::assert_eq!("01:00:00", coding.passed().to_string());
+        assert!(!table.has(ClockType::CountDown, "coding"));
+
+        let saved = serde_json::to_value(&table).unwrap();
+        assert!(saved["stopwatches"]["coding"].is_object());
+        assert!(saved["count_downs"].as_object().unwrap().is_empty());
+        assert!(saved["settings"].is_object());
     }
 }
@@ -0,0 +1,97 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::timing::{Budget, Clock, CountDown, Pomodoro, Sequence, Settings, Stopwatch};
+
+use super::ClockTable;
+
+/// Clock table as found in a state file. Every kind of clock has its own map.
+///
+/// State files written before pomodoros, sequences, budgets and settings existed only contain
+/// stopwatches and count downs.
+#[derive(Deserialize)]
+pub struct PersistedClockTable<T>
+where
+    T: Default,
+{
+    #[serde(default)]
+    stopwatches: HashMap<String, Stopwatch<T>>,
+    #[serde(default)]
+    count_downs: HashMap<String, CountDown<T>>,
+    #[serde(default)]
+    pomodoros: HashMap<String, Pomodoro<T>>,
+    #[serde(default)]
+    sequences: HashMap<String, Sequence<T>>,
+    #[serde(default)]
+    budgets: HashMap<String, Budget<T>>,
+    #[serde(default)]
+    settings: Settings,
+}
+
+/// Same layout as [`PersistedClockTable`] but borrowed from a clock table for saving
+#[derive(Serialize)]
+pub struct PersistedClockTableRef<'a, T>
+where
+    T: Default,
+{
+    stopwatches: BTreeMap<&'a str, &'a Stopwatch<T>>,
+    count_downs: BTreeMap<&'a str, &'a CountDown<T>>,
+    pomodoros: BTreeMap<&'a str, &'a Pomodoro<T>>,
+    sequences: BTreeMap<&'a str, &'a Sequence<T>>,
+    budgets: BTreeMap<&'a str, &'a Budget<T>>,
+    settings: &'a Settings,
+}
+
+impl<T> From<PersistedClockTable<T>> for ClockTable<T>
+where
+    T: Default,
+{
+    fn from(value: PersistedClockTable<T>) -> Self {
+        fn into_clocks<T: Default, C: Into<Clock<T>>>(
+            clocks: HashMap<String, C>,
+        ) -> impl Iterator<Item = (String, Clock<T>)> {
+            clocks.into_iter().map(|(name, clock)| (name, clock.into()))
+        }
+        let clocks = into_clocks(value.stopwatches)
+            .chain(into_clocks(value.count_downs))
+            .chain(into_clocks(value.pomodoros))
+            .chain(into_clocks(value.sequences))
+            .chain(into_clocks(value.budgets))
+            .map(|(name, clock)| ((clock.clock_type(), name), clock))
+            .collect();
+        let mut table = Self {
+            clocks,
+            settings: value.settings,
+        };
+        table.set_time_jump_policy(table.settings.time_jump_policy());
+        table
+    }
+}
+
+impl<'a, T> From<&'a ClockTable<T>> for PersistedClockTableRef<'a, T>
+where
+    T: Default,
+{
+    fn from(value: &'a ClockTable<T>) -> Self {
+        let mut persisted = Self {
+            stopwatches: BTreeMap::new(),
+            count_downs: BTreeMap::new(),
+            pomodoros: BTreeMap::new(),
+            sequences: BTreeMap::new(),
+            budgets: BTreeMap::new(),
+            settings: &value.settings,
+        };
+        for ((_, name), clock) in value.clocks.iter() {
+            let name = name.as_str();
+            match clock {
+                Clock::Stopwatch(stopwatch) => _ = persisted.stopwatches.insert(name, stopwatch),
+                Clock::CountDown(count_down) => _ = persisted.count_downs.insert(name, count_down),
+                Clock::Pomodoro(pomodoro) => _ = persisted.pomodoros.insert(name, pomodoro),
+                Clock::Sequence(sequence) => _ = persisted.sequences.insert(name, sequence),
+                Clock::Budget(budget) => _ = persisted.budgets.insert(name, budget),
+            }
+        }
+        persisted
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// Kinds of clocks kept in a clock table
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ClockType {
+    Stopwatch,
+    CountDown,
+    Pomodoro,
+    Sequence,
+    Budget,
+}
+
+impl ClockType {
+    pub const ALL: [ClockType; 5] = [
+        ClockType::Stopwatch,
+        ClockType::CountDown,
+        ClockType::Pomodoro,
+        ClockType::Sequence,
+        ClockType::Budget,
+    ];
+}
+
+impl std::fmt::Display for ClockType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClockType::Stopwatch => f.write_str("stop watch"),
+            ClockType::CountDown => f.write_str("count down"),
+            ClockType::Pomodoro => f.write_str("pomodoro"),
+            ClockType::Sequence => f.write_str("sequence"),
+            ClockType::Budget => f.write_str("budget"),
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 
 use super::{
-    ClockDuration, InvalidDateInFuture, Stopwatch, TimeImpl, Timer, UtcDateTime, UtcTimeImpl,
+    Adjustment, ChronoDuration, ClockDuration, ClockState, InvalidAlarmDate, InvalidDateInFuture,
+    InvalidSegmentEdit, Lap, RepeatPolicy, SegmentEdit, Stopwatch, TimeChange, TimeImpl,
+    TimeSegment, Timer, UtcDateTime, UtcTimeImpl,
 };
 
 #[derive(Serialize, Deserialize)]
@@ -11,6 +13,16 @@ where
 {
     stopwatch: Stopwatch<T>,
     time: ClockDuration,
+    /// If true, the count down keeps counting after reaching zero
+    #[serde(default)]
+    overrun: bool,
+    #[serde(default)]
+    repeat: RepeatPolicy,
+    /// Moment at which an alarm reaches zero regardless of pauses
+    #[serde(default)]
+    alarm: Option<UtcDateTime>,
+    #[serde(default)]
+    time_changes: Vec<TimeChange>,
 }
 
 impl<T> std::fmt::Debug for CountDown<T>
@@ -21,6 +33,10 @@ where
         f.debug_struct("CountDown")
             .field("stopwatch", &self.stopwatch)
             .field("time", &self.time)
+            .field("overrun", &self.overrun)
+            .field("repeat", &self.repeat)
+            .field("alarm", &self.alarm)
+            .field("time_changes", &self.time_changes)
             .finish()
     }
 }
@@ -28,7 +44,7 @@ where
 impl CountDown {
     pub fn new(time: ClockDuration) -> Self {
         let stopwatch: Stopwatch = Stopwatch::new();
-        Self { stopwatch, time }
+        Self::from_stopwatch(stopwatch, time)
     }
 
     pub fn new_with_start(
@@ -36,7 +52,18 @@ impl CountDown {
         start: UtcDateTime,
     ) -> Result<Self, InvalidDateInFuture> {
         let stopwatch: Stopwatch = Stopwatch::new_with_impl_and_start_date(UtcTimeImpl, start)?;
-        Ok(Self { stopwatch, time })
+        Ok(Self::from_stopwatch(stopwatch, time))
+    }
+
+    /// Count down which starts counting down at the given moment
+    pub fn new_scheduled(time: ClockDuration, start: UtcDateTime) -> Self {
+        Self::new_scheduled_with_impl(UtcTimeImpl, time, start)
+    }
+
+    /// Count down which reaches zero at the given moment.
+    /// See [`CountDown::new_alarm_with_impl`].
+    pub fn new_alarm(at: UtcDateTime, follows_pauses: bool) -> Result<Self, InvalidAlarmDate> {
+        Self::new_alarm_with_impl(UtcTimeImpl, at, follows_pauses)
     }
 }
 
@@ -50,19 +77,217 @@ where
 
     pub fn new_with_impl(time_impl: T, time: ClockDuration) -> Self {
         let stopwatch = Stopwatch::new_with_impl(time_impl);
-        Self { stopwatch, time }
+        Self::from_stopwatch(stopwatch, time)
+    }
+
+    /// Count down which starts counting down at the given moment.
+    /// Until then it is scheduled and its left time stays the same.
+    pub fn new_scheduled_with_impl(time_impl: T, time: ClockDuration, start: UtcDateTime) -> Self {
+        let stopwatch = Stopwatch::new_with_impl_and_scheduled_start(time_impl, start);
+        Self::from_stopwatch(stopwatch, time)
+    }
+
+    /// Count down which reaches zero at the given moment.
+    ///
+    /// If `follows_pauses` is true, the alarm is a normal count down whose time is the duration until
+    /// the given moment. Pausing it postpones the moment it reaches zero.
+    /// Otherwise pausing and resuming have no effect and the alarm always goes off at the given moment.
+    pub fn new_alarm_with_impl(
+        time_impl: T,
+        at: UtcDateTime,
+        follows_pauses: bool,
+    ) -> Result<Self, InvalidAlarmDate> {
+        let now = time_impl.now();
+        if at <= now {
+            return Err(InvalidAlarmDate(at));
+        }
+        let stopwatch = Stopwatch::new_with_impl(time_impl);
+        let mut count_down = Self::from_stopwatch(stopwatch, (at - now).into());
+        if !follows_pauses {
+            count_down.alarm = Some(at);
+        }
+        Ok(count_down)
+    }
+
+    fn from_stopwatch(stopwatch: Stopwatch<T>, time: ClockDuration) -> Self {
+        Self {
+            stopwatch,
+            time,
+            overrun: false,
+            repeat: RepeatPolicy::Once,
+            alarm: None,
+            time_changes: Vec::new(),
+        }
+    }
+
+    /// Extends the count down by the given amount or shortens it if the amount is negative.
+    /// The time never becomes negative.
+    pub fn extend_by(&mut self, amount: ClockDuration) {
+        self.set_count_down_time(self.time + amount);
+    }
+
+    /// Sets the time the count down counts down from.
+    /// Passed time is kept. An alarm which ignores pauses is moved by the difference.
+    pub fn set_count_down_time(&mut self, time: ClockDuration) {
+        let time = time.max(ClockDuration::default());
+        let change = TimeChange::new(self.stopwatch.now(), self.time, time);
+        if let Some(at) = self.alarm.as_mut() {
+            *at += ChronoDuration::from(change.difference());
+        }
+        self.time = time;
+        self.time_changes.push(change);
+    }
+
+    /// All changes of the count down time from the oldest to the newest
+    pub fn time_changes(&self) -> &[TimeChange] {
+        &self.time_changes
     }
 
+    /// Moment of an alarm which ignores pauses
+    pub fn alarm(&self) -> Option<UtcDateTime> {
+        self.alarm
+    }
+
+    /// Moment at which the count down reaches or reached zero.
+    /// None if the count down is paused before reaching zero since the moment is not known yet.
+    pub fn target(&self) -> Option<UtcDateTime> {
+        if let Some(at) = self.alarm {
+            return Some(at - ChronoDuration::from(self.stopwatch.adjusted_by()));
+        }
+        match self.finished_at() {
+            Some(finished) => Some(finished),
+            None if self.is_paused() => None,
+            None => {
+                let counting_from = self.stopwatch.now().max(self.start_moment());
+                let left = self.stopwatch.speed().unscale(self.left_time());
+                Some(counting_from + ChronoDuration::from(left))
+            }
+        }
+    }
+
+    /// Passed time without clamping to the total time.
+    /// An alarm which ignores pauses only depends on the current moment.
+    fn actual_passed(&self) -> ClockDuration {
+        match self.alarm {
+            Some(at) => {
+                let until_alarm: ClockDuration = (at - self.stopwatch.now()).into();
+                let passed = self.time - until_alarm + self.stopwatch.adjusted_by();
+                passed.max(ClockDuration::default())
+            }
+            None => self.stopwatch.passed(),
+        }
+    }
+
+    /// Time left in the current cycle.
+    /// Negative if the count down is overdue in overrun mode.
     pub fn left_time(&self) -> ClockDuration {
-        let left = self.time - self.passed();
-        debug_assert!(left.total_secs() >= 0);
-        debug_assert!(left.nanos_part() >= 0);
+        let actual_passed = self.actual_passed();
+        let left = match self.total_time() {
+            Some(total) if actual_passed >= total => {
+                if self.overrun {
+                    total - actual_passed
+                } else {
+                    ClockDuration::default()
+                }
+            }
+            _ if self.time.is_zero() => ClockDuration::default(),
+            _ => self.time - (actual_passed % self.time),
+        };
+        debug_assert!(self.overrun || !left.is_negative());
         left
     }
 
+    pub fn repeat_policy(&self) -> RepeatPolicy {
+        self.repeat
+    }
+
+    pub fn set_repeat_policy(&mut self, repeat: RepeatPolicy) {
+        self.repeat = repeat;
+    }
+
+    /// Duration of all cycles together. None if the count down repeats forever.
+    pub fn total_time(&self) -> Option<ClockDuration> {
+        self.repeat.cycles().map(|cycles| self.time * cycles)
+    }
+
+    /// Index of the current cycle starting with 0
+    pub fn current_cycle(&self) -> u32 {
+        if self.time.is_zero() {
+            return 0;
+        }
+        let completed = self.actual_passed().div_duration(self.time);
+        let completed = u32::try_from(completed).unwrap_or(u32::MAX);
+        match self.repeat.cycles() {
+            Some(cycles) => completed.min(cycles - 1),
+            None => completed,
+        }
+    }
+
+    /// Cycles still to come after the current one. None if the count down repeats forever.
+    pub fn remaining_cycles(&self) -> Option<u32> {
+        self.repeat
+            .cycles()
+            .map(|cycles| cycles - 1 - self.current_cycle())
+    }
+
+    pub fn allows_overrun(&self) -> bool {
+        self.overrun
+    }
+
+    /// In overrun mode, the count down continues to count past zero instead of stopping.
+    pub fn set_overrun(&mut self, overrun: bool) {
+        self.overrun = overrun;
+    }
+
+    /// Count down reached zero in overrun mode and keeps counting
+    pub fn is_overdue(&self) -> bool {
+        self.overrun
+            && self
+                .total_time()
+                .is_some_and(|total| self.actual_passed() > total)
+    }
+
+    /// How long the count down has been counting past zero.
+    /// Zero if the count down is not overdue.
+    pub fn overrun_time(&self) -> ClockDuration {
+        match self.total_time() {
+            Some(total) if self.is_overdue() => self.actual_passed() - total,
+            _ => ClockDuration::default(),
+        }
+    }
+
+    pub fn laps(&self) -> &[Lap] {
+        self.stopwatch.laps()
+    }
+
+    pub fn lap(&mut self) -> Lap {
+        self.stopwatch.lap()
+    }
+
+    pub fn edit(&mut self, edit: SegmentEdit) -> Result<(), InvalidSegmentEdit> {
+        if self.alarm.is_some() {
+            return Err(InvalidSegmentEdit::FixedAlarm);
+        }
+        match self.total_time() {
+            Some(total) if !self.overrun => self
+                .stopwatch
+                .edit_up_to(edit, total - self.stopwatch.adjusted_by()),
+            _ => self.stopwatch.edit(edit),
+        }
+    }
+}
+
+impl<T> CountDown<T>
+where
+    T: Default,
+{
     pub fn stopwatch(&self) -> &Stopwatch<T> {
         &self.stopwatch
     }
+
+    pub(crate) fn mut_stopwatch(&mut self) -> &mut Stopwatch<T> {
+        &mut self.stopwatch
+    }
 }
 
 impl<T> Timer for CountDown<T>
@@ -70,20 +295,32 @@ where
     T: TimeImpl + Default,
 {
     fn passed(&self) -> ClockDuration {
-        let actual_passed = self.stopwatch.passed();
-        actual_passed.min(self.time)
+        let actual_passed = self.actual_passed();
+        match self.total_time() {
+            Some(total) if !self.overrun => actual_passed.min(total),
+            _ => actual_passed,
+        }
     }
 
     fn pause(&mut self) {
-        self.stopwatch.pause();
+        if self.alarm.is_none() {
+            self.stopwatch.pause();
+        }
     }
 
     fn resume(&mut self) {
-        self.stopwatch.resume();
+        if self.alarm.is_none() {
+            self.stopwatch.resume();
+        }
     }
 
+    /// An alarm which ignores pauses keeps its moment and only starts over from now on.
     fn reset(&mut self) {
         self.stopwatch.reset();
+        if let Some(at) = self.alarm {
+            let until_alarm: ClockDuration = (at - self.stopwatch.now()).into();
+            self.time = until_alarm.max(ClockDuration::default());
+        }
     }
 
     fn is_paused(&self) -> bool {
@@ -109,6 +346,49 @@ where
     fn start_moment(&self) -> super::UtcDateTime {
         self.stopwatch.start_moment()
     }
+
+    fn running_segments(&self) -> Vec<TimeSegment> {
+        match self.total_time() {
+            Some(total) if !self.overrun => self
+                .stopwatch
+                .segments_up_to(total - self.stopwatch.adjusted_by()),
+            _ => self.stopwatch.running_segments(),
+        }
+    }
+
+    fn adjust(&mut self, amount: ClockDuration, reason: Option<String>) {
+        self.stopwatch.adjust(amount, reason);
+    }
+
+    fn adjustments(&self) -> &[Adjustment] {
+        self.stopwatch.adjustments()
+    }
+
+    fn starts_in(&self) -> Option<ClockDuration> {
+        self.stopwatch.starts_in()
+    }
+
+    fn state(&self) -> ClockState {
+        match self.stopwatch.state() {
+            ClockState::Scheduled => ClockState::Scheduled,
+            _ if self.finished_at().is_some() => ClockState::Finished,
+            other => other,
+        }
+    }
+
+    /// Derived from the running segments and adjustments.
+    /// Exact even if nobody looked at the count down when it reached zero.
+    /// None for a count down which repeats forever.
+    fn finished_at(&self) -> Option<UtcDateTime> {
+        let total = self.total_time()?;
+        match self.alarm {
+            Some(_) => {
+                let at = self.target()?;
+                (at <= self.stopwatch.now()).then_some(at)
+            }
+            None => self.stopwatch.moment_at_passed(total),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -148,4 +428,283 @@ mod testing {
         // Count should be finished now
         assert_left_time(&count_down, ClockDuration::default());
     }
+
+    #[test]
+    fn running_segments_end_at_zero() {
+        let intial_time = ClockDuration::new_secs_mins_hours(Some(1), None, None);
+        let (mut count_down, mut setter) = set_up_counte_mock("2020-02-11 12:00:00", intial_time);
+        add_to_now(&mut setter, TimeDelta::minutes(40));
+        count_down.pause();
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        count_down.resume();
+        add_to_now(&mut setter, TimeDelta::hours(2));
+
+        let expected = vec![
+            TimeSegment::new(
+                new_utc_moment("2020-02-11 12:00:00"),
+                new_utc_moment("2020-02-11 12:40:00"),
+            ),
+            TimeSegment::new(
+                new_utc_moment("2020-02-11 12:50:00"),
+                new_utc_moment("2020-02-11 13:10:00"),
+            ),
+        ];
+        pretty_assertions::assert_eq!(expected, count_down.running_segments());
+    }
+
+    #[test]
+    fn edit_segments_of_count_down_past_zero() {
+        let intial_time = ClockDuration::new_secs_mins_hours(Some(1), None, None);
+        let (mut count_down, mut setter) = set_up_counte_mock("2020-02-11 12:00:00", intial_time);
+        add_to_now(&mut setter, TimeDelta::minutes(40));
+        count_down.pause();
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        count_down.resume();
+        add_to_now(&mut setter, TimeDelta::minutes(40));
+        count_down.pause();
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        count_down.resume();
+        add_to_now(&mut setter, TimeDelta::minutes(20));
+
+        // The third segment lies past zero and is not shown
+        pretty_assertions::assert_eq!(2, count_down.running_segments().len());
+        pretty_assertions::assert_eq!(
+            Err(InvalidSegmentEdit::NoSegment(2)),
+            count_down.edit(SegmentEdit::Remove { segment: 2 })
+        );
+
+        count_down
+            .edit(SegmentEdit::MoveEnd {
+                segment: 0,
+                to: new_utc_moment("2020-02-11 12:10:00"),
+            })
+            .unwrap();
+        let expected = vec![
+            TimeSegment::new(
+                new_utc_moment("2020-02-11 12:00:00"),
+                new_utc_moment("2020-02-11 12:10:00"),
+            ),
+            TimeSegment::new(
+                new_utc_moment("2020-02-11 12:50:00"),
+                new_utc_moment("2020-02-11 13:30:00"),
+            ),
+            TimeSegment::new(
+                new_utc_moment("2020-02-11 13:40:00"),
+                new_utc_moment("2020-02-11 13:50:00"),
+            ),
+        ];
+        pretty_assertions::assert_eq!(expected, count_down.running_segments());
+
+        count_down.edit(SegmentEdit::Remove { segment: 2 }).unwrap();
+        pretty_assertions::assert_eq!("00:50:00", count_down.passed().to_string());
+        pretty_assertions::assert_eq!(2, count_down.running_segments().len());
+    }
+
+    #[test]
+    fn finish_at_speed() {
+        let intial_time = ClockDuration::new_secs_mins_hours(Some(1), None, None);
+        let (mut count_down, mut setter) = set_up_counte_mock("2020-02-11 12:00:00", intial_time);
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        count_down.mut_stopwatch().set_speed("2.5".parse().unwrap());
+        pretty_assertions::assert_eq!(
+            Some(new_utc_moment("2020-02-11 12:30:00")),
+            count_down.target()
+        );
+        add_to_now(&mut setter, TimeDelta::hours(1));
+        pretty_assertions::assert_eq!(
+            Some(new_utc_moment("2020-02-11 12:30:00")),
+            count_down.finished_at()
+        );
+        assert_left_time(&count_down, ClockDuration::default());
+    }
+
+    #[test]
+    fn overrun_past_zero() {
+        let intial_time = ClockDuration::new_secs_mins_hours(Some(1), None, None);
+        let (mut count_down, mut setter) = set_up_counte_mock("2020-02-11 12:00:00", intial_time);
+        count_down.set_overrun(true);
+        add_to_now(&mut setter, TimeDelta::minutes(50));
+        assert!(!count_down.is_overdue());
+        assert_left_time(
+            &count_down,
+            ClockDuration::new_secs_mins_hours(None, Some(10), None),
+        );
+
+        add_to_now(&mut setter, TimeDelta::minutes(22) + TimeDelta::seconds(30));
+        assert!(count_down.is_overdue());
+        pretty_assertions::assert_eq!("-00:12:30", count_down.left_time().to_string());
+        pretty_assertions::assert_eq!("00:12:30", count_down.overrun_time().to_string());
+        pretty_assertions::assert_eq!("01:12:30", count_down.passed().to_string());
+    }
+
+    #[test]
+    fn repeat_for_fixed_cycles() {
+        let ten_minutes = ClockDuration::new_secs_mins_hours(None, Some(10), None);
+        let (mut count_down, mut setter) = set_up_counte_mock("2020-02-11 12:00:00", ten_minutes);
+        count_down.set_repeat_policy(RepeatPolicy::Times(3.try_into().unwrap()));
+        pretty_assertions::assert_eq!(0, count_down.current_cycle());
+        pretty_assertions::assert_eq!(Some(2), count_down.remaining_cycles());
+
+        add_to_now(&mut setter, TimeDelta::minutes(14));
+        pretty_assertions::assert_eq!(1, count_down.current_cycle());
+        pretty_assertions::assert_eq!(Some(1), count_down.remaining_cycles());
+        assert_left_time(
+            &count_down,
+            ClockDuration::new_secs_mins_hours(None, Some(6), None),
+        );
+
+        add_to_now(&mut setter, TimeDelta::minutes(30));
+        pretty_assertions::assert_eq!(2, count_down.current_cycle());
+        pretty_assertions::assert_eq!(Some(0), count_down.remaining_cycles());
+        assert_left_time(&count_down, ClockDuration::default());
+        pretty_assertions::assert_eq!("00:30:00", count_down.passed().to_string());
+    }
+
+    #[test]
+    fn repeat_forever() {
+        let ten_minutes = ClockDuration::new_secs_mins_hours(None, Some(10), None);
+        let (mut count_down, mut setter) = set_up_counte_mock("2020-02-11 12:00:00", ten_minutes);
+        count_down.set_repeat_policy(RepeatPolicy::Forever);
+
+        add_to_now(&mut setter, TimeDelta::minutes(123));
+        pretty_assertions::assert_eq!(12, count_down.current_cycle());
+        pretty_assertions::assert_eq!(None, count_down.remaining_cycles());
+        assert_left_time(
+            &count_down,
+            ClockDuration::new_secs_mins_hours(None, Some(7), None),
+        );
+        assert!(!count_down.is_overdue());
+    }
+
+    #[test]
+    fn alarm_ignores_pauses() {
+        let time_impl = MockTimeImpl::new(new_utc_moment("2026-12-24 12:00:00"));
+        let mut setter = time_impl.clone();
+        let at = new_utc_moment("2026-12-24 18:00:00");
+        let mut alarm = CountDown::new_alarm_with_impl(time_impl, at, false).unwrap();
+        pretty_assertions::assert_eq!("06:00:00", alarm.count_down_time().to_string());
+
+        add_to_now(&mut setter, TimeDelta::hours(1));
+        alarm.pause();
+        add_to_now(&mut setter, TimeDelta::hours(2));
+        assert!(!alarm.is_paused());
+        pretty_assertions::assert_eq!("03:00:00", alarm.left_time().to_string());
+        pretty_assertions::assert_eq!(Some(at), alarm.target());
+        assert_eq!(
+            Err(InvalidSegmentEdit::FixedAlarm),
+            alarm.edit(SegmentEdit::Remove { segment: 0 })
+        );
+
+        add_to_now(&mut setter, TimeDelta::hours(4));
+        assert_left_time(&alarm, ClockDuration::default());
+        pretty_assertions::assert_eq!("06:00:00", alarm.passed().to_string());
+    }
+
+    #[test]
+    fn alarm_follows_pauses() {
+        let time_impl = MockTimeImpl::new(new_utc_moment("2026-12-24 12:00:00"));
+        let mut setter = time_impl.clone();
+        let at = new_utc_moment("2026-12-24 18:00:00");
+        let mut alarm = CountDown::new_alarm_with_impl(time_impl, at, true).unwrap();
+
+        add_to_now(&mut setter, TimeDelta::hours(1));
+        alarm.pause();
+        pretty_assertions::assert_eq!(None, alarm.target());
+        add_to_now(&mut setter, TimeDelta::hours(2));
+        alarm.resume();
+        pretty_assertions::assert_eq!("05:00:00", alarm.left_time().to_string());
+        pretty_assertions::assert_eq!(Some(new_utc_moment("2026-12-24 20:00:00")), alarm.target());
+    }
+
+    #[test]
+    fn reject_alarm_in_past() {
+        let time_impl = MockTimeImpl::new(new_utc_moment("2026-12-24 12:00:00"));
+        let at = new_utc_moment("2026-12-24 11:00:00");
+        let actual = CountDown::new_alarm_with_impl(time_impl, at, false);
+        assert_eq!(Some(InvalidAlarmDate(at)), actual.err());
+    }
+
+    #[test]
+    fn scheduled_count_down() {
+        let time_impl = MockTimeImpl::new(new_utc_moment("2020-02-11 12:00:00"));
+        let mut setter = time_impl.clone();
+        let ten_minutes = ClockDuration::new_secs_mins_hours(None, Some(10), None);
+        let start = new_utc_moment("2020-02-11 14:00:00");
+        let count_down = CountDown::new_scheduled_with_impl(time_impl, ten_minutes, start);
+
+        assert_left_time(&count_down, ten_minutes);
+        pretty_assertions::assert_eq!(
+            Some(new_utc_moment("2020-02-11 14:10:00")),
+            count_down.target()
+        );
+        add_to_now(&mut setter, TimeDelta::minutes(124));
+        pretty_assertions::assert_eq!(None, count_down.starts_in());
+        assert_left_time(
+            &count_down,
+            ClockDuration::new_secs_mins_hours(None, Some(6), None),
+        );
+    }
+
+    #[test]
+    fn snooze_count_down() {
+        let ten_minutes = ClockDuration::new_secs_mins_hours(None, Some(10), None);
+        let (mut count_down, mut setter) = set_up_counte_mock("2020-02-11 12:00:00", ten_minutes);
+        add_to_now(&mut setter, TimeDelta::minutes(9));
+        count_down.extend_by(ClockDuration::new_secs_mins_hours(None, Some(5), None));
+        pretty_assertions::assert_eq!("00:06:00", count_down.left_time().to_string());
+
+        add_to_now(&mut setter, TimeDelta::minutes(1));
+        count_down.pause();
+        count_down.set_count_down_time(ClockDuration::new_secs_mins_hours(None, Some(30), None));
+        pretty_assertions::assert_eq!("00:20:00", count_down.left_time().to_string());
+        count_down.extend_by(-ClockDuration::new_secs_mins_hours(Some(1), None, None));
+        assert_left_time(&count_down, ClockDuration::default());
+
+        let changes = count_down.time_changes();
+        pretty_assertions::assert_eq!(3, changes.len());
+        pretty_assertions::assert_eq!(
+            TimeChange::new(
+                new_utc_moment("2020-02-11 12:09:00"),
+                ten_minutes,
+                ClockDuration::new_secs_mins_hours(None, Some(15), None)
+            ),
+            changes[0]
+        );
+        pretty_assertions::assert_eq!(ClockDuration::default(), changes[2].new_time());
+    }
+
+    #[test]
+    fn snooze_alarm() {
+        let time_impl = MockTimeImpl::new(new_utc_moment("2026-12-24 12:00:00"));
+        let at = new_utc_moment("2026-12-24 18:00:00");
+        let mut alarm = CountDown::new_alarm_with_impl(time_impl, at, false).unwrap();
+        alarm.extend_by(ClockDuration::new_secs_mins_hours(None, Some(5), None));
+        pretty_assertions::assert_eq!(Some(new_utc_moment("2026-12-24 18:05:00")), alarm.target());
+        pretty_assertions::assert_eq!("06:05:00", alarm.left_time().to_string());
+    }
+
+    #[test]
+    fn finished_at_from_pause_history() {
+        let intial_time = ClockDuration::new_secs_mins_hours(Some(1), None, None);
+        let (mut count_down, mut setter) = set_up_counte_mock("2020-02-11 12:00:00", intial_time);
+        pretty_assertions::assert_eq!(ClockState::Running, count_down.state());
+        add_to_now(&mut setter, TimeDelta::minutes(40));
+        count_down.pause();
+        pretty_assertions::assert_eq!(ClockState::Paused, count_down.state());
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        count_down.resume();
+        pretty_assertions::assert_eq!(None, count_down.finished_at());
+
+        // Nobody looks at the count down when it reaches zero
+        add_to_now(&mut setter, TimeDelta::hours(5));
+        pretty_assertions::assert_eq!(ClockState::Finished, count_down.state());
+        pretty_assertions::assert_eq!(
+            Some(new_utc_moment("2020-02-11 13:10:00")),
+            count_down.finished_at()
+        );
+
+        count_down.set_repeat_policy(RepeatPolicy::Forever);
+        pretty_assertions::assert_eq!(ClockState::Running, count_down.state());
+        pretty_assertions::assert_eq!(None, count_down.finished_at());
+    }
 }
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// Decides which running clocks are paused once another clock is resumed or created
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ExclusiveMode {
+    /// Any number of clocks can run at once
+    #[default]
+    Off,
+    /// Only one clock runs at a time
+    Global,
+    /// Only one clock per group runs at a time. Clocks without a group are not affected.
+    Group,
+}
+
+impl std::fmt::Display for ExclusiveMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExclusiveMode::Off => f.write_str("off"),
+            ExclusiveMode::Global => f.write_str("global"),
+            ExclusiveMode::Group => f.write_str("group"),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ClockDuration, UtcDateTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct Lap {
+    recorded_at: UtcDateTime,
+    split: ClockDuration,
+    total: ClockDuration,
+}
+
+impl Lap {
+    pub fn new(recorded_at: UtcDateTime, split: ClockDuration, total: ClockDuration) -> Self {
+        Self {
+            recorded_at,
+            split,
+            total,
+        }
+    }
+
+    pub fn recorded_at(&self) -> UtcDateTime {
+        self.recorded_at
+    }
+
+    /// Time passed since the previous lap or the start if this is the first lap
+    pub fn split(&self) -> ClockDuration {
+        self.split
+    }
+
+    /// Time passed since the start of the stopwatch up to this lap
+    pub fn total(&self) -> ClockDuration {
+        self.total
+    }
+}
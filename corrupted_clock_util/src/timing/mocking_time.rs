@@ -1,28 +1,67 @@
-use std::{cell::RefCell, rc::Rc};
+use std::sync::{Arc, PoisonError, RwLock};
 
 use chrono::TimeDelta;
 
 use super::{TimeImpl, UtcDateTime};
 
+/// Settable moment which is shared between all its clones, also across threads.
+/// Clocks created with a clone follow every change made via another clone.
 #[derive(Default, Clone, Debug)]
-pub struct MockTimeImpl(Rc<RefCell<UtcDateTime>>);
+pub struct MockTimeImpl(Arc<RwLock<UtcDateTime>>);
 
 impl TimeImpl for MockTimeImpl {
     fn now(&self) -> UtcDateTime {
-        self.0.borrow().clone()
+        *self.0.read().unwrap_or_else(PoisonError::into_inner)
     }
 }
 
 impl MockTimeImpl {
     pub fn new(current_now: UtcDateTime) -> Self {
-        let now = Rc::new(RefCell::new(current_now));
+        let now = Arc::new(RwLock::new(current_now));
         Self(now)
     }
 
     pub fn add_to_now(&self, to_add: TimeDelta) {
-        *self.0.borrow_mut() += to_add;
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) += to_add;
     }
     pub fn set_now(&self, new_now: UtcDateTime) {
-        *self.0.borrow_mut() = new_now;
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) = new_now;
+    }
+}
+
+/// Moment which never changes. Every copy is independent of the others.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedTimeImpl(UtcDateTime);
+
+impl TimeImpl for FixedTimeImpl {
+    fn now(&self) -> UtcDateTime {
+        self.0
+    }
+}
+
+impl FixedTimeImpl {
+    pub fn new(now: UtcDateTime) -> Self {
+        Self(now)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::timing::{test_utils, Stopwatch, Timer};
+
+    use super::*;
+
+    #[test]
+    fn share_mocked_time_between_threads() {
+        let time_impl = MockTimeImpl::new(test_utils::new_utc_moment("2024-05-01 08:00:00"));
+        let stopwatch = Stopwatch::new_with_impl(time_impl.clone());
+        let setter = time_impl.clone();
+        std::thread::spawn(move || setter.add_to_now(TimeDelta::minutes(5)))
+            .join()
+            .unwrap();
+        let passed = std::thread::spawn(move || stopwatch.passed().to_string())
+            .join()
+            .unwrap();
+        pretty_assertions::assert_eq!("00:05:00", passed);
     }
 }
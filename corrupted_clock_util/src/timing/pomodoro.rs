@@ -0,0 +1,459 @@
+use std::num::NonZeroU32;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Adjustment, ClockDuration, ClockState, InvalidDateInFuture, InvalidSegmentEdit, Lap,
+    SegmentEdit, Stopwatch, TimeImpl, TimeSegment, Timer, UtcDateTime, UtcTimeImpl,
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PomodoroPhase {
+    Work,
+    ShortBreak,
+    LongBreak,
+}
+
+impl std::fmt::Display for PomodoroPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PomodoroPhase::Work => f.write_str("work"),
+            PomodoroPhase::ShortBreak => f.write_str("short break"),
+            PomodoroPhase::LongBreak => f.write_str("long break"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct PomodoroConfig {
+    work: ClockDuration,
+    short_break: ClockDuration,
+    long_break: ClockDuration,
+    /// A long break follows after this many work phases
+    long_break_interval: NonZeroU32,
+}
+
+impl Default for PomodoroConfig {
+    fn default() -> Self {
+        let minutes = |minutes| ClockDuration::new_secs_mins_hours(None, Some(minutes), None);
+        Self {
+            work: minutes(25),
+            short_break: minutes(5),
+            long_break: minutes(15),
+            long_break_interval: NonZeroU32::new(4).unwrap(),
+        }
+    }
+}
+
+impl PomodoroConfig {
+    pub fn new(
+        work: ClockDuration,
+        short_break: ClockDuration,
+        long_break: ClockDuration,
+        long_break_interval: NonZeroU32,
+    ) -> Self {
+        Self {
+            work,
+            short_break,
+            long_break,
+            long_break_interval,
+        }
+    }
+
+    pub fn work(&self) -> ClockDuration {
+        self.work
+    }
+
+    pub fn short_break(&self) -> ClockDuration {
+        self.short_break
+    }
+
+    pub fn long_break(&self) -> ClockDuration {
+        self.long_break
+    }
+
+    pub fn long_break_interval(&self) -> NonZeroU32 {
+        self.long_break_interval
+    }
+
+    pub fn phase_duration(&self, phase: PomodoroPhase) -> ClockDuration {
+        match phase {
+            PomodoroPhase::Work => self.work,
+            PomodoroPhase::ShortBreak => self.short_break,
+            PomodoroPhase::LongBreak => self.long_break,
+        }
+    }
+
+    /// Endless sequence of phases starting with the first work phase
+    fn phases(&self) -> impl Iterator<Item = PomodoroPhase> {
+        let interval = self.long_break_interval.get();
+        (1..=interval)
+            .flat_map(move |work_phase| {
+                let pause = if work_phase == interval {
+                    PomodoroPhase::LongBreak
+                } else {
+                    PomodoroPhase::ShortBreak
+                };
+                [PomodoroPhase::Work, pause]
+            })
+            .cycle()
+    }
+}
+
+/// Finished phase of a pomodoro
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct PhaseRecord {
+    phase: PomodoroPhase,
+    started_at: UtcDateTime,
+    ended_at: UtcDateTime,
+}
+
+impl PhaseRecord {
+    pub fn new(phase: PomodoroPhase, started_at: UtcDateTime, ended_at: UtcDateTime) -> Self {
+        Self {
+            phase,
+            started_at,
+            ended_at,
+        }
+    }
+
+    pub fn phase(&self) -> PomodoroPhase {
+        self.phase
+    }
+
+    pub fn started_at(&self) -> UtcDateTime {
+        self.started_at
+    }
+
+    pub fn ended_at(&self) -> UtcDateTime {
+        self.ended_at
+    }
+}
+
+/// Clock which moves through work and break phases according to its passed time.
+///
+/// Finished phases are recorded in a history which is kept after a reset.
+#[derive(Serialize, Deserialize)]
+pub struct Pomodoro<T = UtcTimeImpl>
+where
+    T: Default,
+{
+    stopwatch: Stopwatch<T>,
+    config: PomodoroConfig,
+    #[serde(default)]
+    history: Vec<PhaseRecord>,
+    /// How many phases of the history belong to the current run of the stopwatch
+    #[serde(default)]
+    recorded_in_run: usize,
+    #[serde(default)]
+    completed_pomodoros: u32,
+}
+
+impl<T> std::fmt::Debug for Pomodoro<T>
+where
+    T: Default,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pomodoro")
+            .field("stopwatch", &self.stopwatch)
+            .field("config", &self.config)
+            .field("history", &self.history)
+            .finish()
+    }
+}
+
+impl Pomodoro {
+    pub fn new(config: PomodoroConfig) -> Self {
+        Self::from_stopwatch(Stopwatch::new(), config)
+    }
+
+    pub fn new_with_start(
+        config: PomodoroConfig,
+        start: UtcDateTime,
+    ) -> Result<Self, InvalidDateInFuture> {
+        let stopwatch = Stopwatch::new_with_impl_and_start_date(UtcTimeImpl, start)?;
+        Ok(Self::from_stopwatch(stopwatch, config))
+    }
+
+    /// Pomodoro which starts with its first work phase at the given moment
+    pub fn new_scheduled(config: PomodoroConfig, start: UtcDateTime) -> Self {
+        Self::from_stopwatch(Stopwatch::new_scheduled(start), config)
+    }
+}
+
+impl<T> Pomodoro<T>
+where
+    T: TimeImpl + Default,
+{
+    pub fn new_with_impl(time_impl: T, config: PomodoroConfig) -> Self {
+        Self::from_stopwatch(Stopwatch::new_with_impl(time_impl), config)
+    }
+
+    fn from_stopwatch(stopwatch: Stopwatch<T>, config: PomodoroConfig) -> Self {
+        Self {
+            stopwatch,
+            config,
+            history: Vec::new(),
+            recorded_in_run: 0,
+            completed_pomodoros: 0,
+        }
+    }
+
+    pub fn config(&self) -> PomodoroConfig {
+        self.config
+    }
+
+    pub fn current_phase(&self) -> PomodoroPhase {
+        self.phase_and_time_left().0
+    }
+
+    pub fn left_in_phase(&self) -> ClockDuration {
+        self.phase_and_time_left().1
+    }
+
+    /// All finished phases, including the ones of runs before a reset
+    pub fn phase_history(&self) -> Vec<PhaseRecord> {
+        let not_recorded = self.finished_phases_of_run().skip(self.recorded_in_run);
+        self.history.iter().copied().chain(not_recorded).collect()
+    }
+
+    /// Number of finished work phases, including the ones of runs before a reset
+    pub fn completed_pomodoros(&self) -> u32 {
+        let not_recorded = self
+            .finished_phases_of_run()
+            .skip(self.recorded_in_run)
+            .filter(|record| record.phase == PomodoroPhase::Work)
+            .count() as u32;
+        self.completed_pomodoros + not_recorded
+    }
+
+    /// Appends the phases finished since the last call to the persisted history
+    pub fn record_finished_phases(&mut self) {
+        let not_recorded: Vec<PhaseRecord> = self
+            .finished_phases_of_run()
+            .skip(self.recorded_in_run)
+            .collect();
+        self.recorded_in_run += not_recorded.len();
+        self.completed_pomodoros += not_recorded
+            .iter()
+            .filter(|record| record.phase == PomodoroPhase::Work)
+            .count() as u32;
+        self.history.extend(not_recorded);
+    }
+
+    pub fn laps(&self) -> &[Lap] {
+        self.stopwatch.laps()
+    }
+
+    pub fn lap(&mut self) -> Lap {
+        self.stopwatch.lap()
+    }
+
+    pub fn edit(&mut self, edit: SegmentEdit) -> Result<(), InvalidSegmentEdit> {
+        self.stopwatch.edit(edit)
+    }
+
+    fn phase_and_time_left(&self) -> (PomodoroPhase, ClockDuration) {
+        let passed = self.stopwatch.passed();
+        let mut phase_end = ClockDuration::default();
+        for phase in self.config.phases() {
+            phase_end = phase_end + self.config.phase_duration(phase);
+            if phase_end > passed {
+                return (phase, phase_end - passed);
+            }
+            if self.cycle_duration().is_zero() {
+                break;
+            }
+        }
+        (PomodoroPhase::Work, ClockDuration::default())
+    }
+
+    fn cycle_duration(&self) -> ClockDuration {
+        let interval = self.config.long_break_interval.get();
+        self.config.work * interval
+            + self.config.short_break * (interval - 1)
+            + self.config.long_break
+    }
+
+    fn finished_phases_of_run(&self) -> impl Iterator<Item = PhaseRecord> + '_ {
+        let passed = self.stopwatch.passed();
+        let has_progress = !self.cycle_duration().is_zero();
+        self.config
+            .phases()
+            .take_while(move |_| has_progress)
+            .scan(ClockDuration::default(), |phase_start, phase| {
+                let start = *phase_start;
+                *phase_start = start + self.config.phase_duration(phase);
+                Some((phase, start, *phase_start))
+            })
+            .take_while(move |(_, _, end)| *end <= passed)
+            .filter_map(|(phase, start, end)| {
+                Some(PhaseRecord {
+                    phase,
+                    started_at: self.stopwatch.moment_at_passed(start)?,
+                    ended_at: self.stopwatch.moment_at_passed(end)?,
+                })
+            })
+    }
+}
+
+impl<T> Pomodoro<T>
+where
+    T: Default,
+{
+    pub fn stopwatch(&self) -> &Stopwatch<T> {
+        &self.stopwatch
+    }
+
+    pub(crate) fn mut_stopwatch(&mut self) -> &mut Stopwatch<T> {
+        &mut self.stopwatch
+    }
+}
+
+impl<T> Timer for Pomodoro<T>
+where
+    T: TimeImpl + Default,
+{
+    fn created_at(&self) -> UtcDateTime {
+        self.stopwatch.created_at()
+    }
+
+    fn start_moment(&self) -> UtcDateTime {
+        self.stopwatch.start_moment()
+    }
+
+    fn last_resumed_at(&self) -> Option<UtcDateTime> {
+        self.stopwatch.last_resumed_at()
+    }
+
+    fn last_paused_at(&self) -> Option<UtcDateTime> {
+        self.stopwatch.last_paused_at()
+    }
+
+    fn passed(&self) -> ClockDuration {
+        self.stopwatch.passed()
+    }
+
+    fn paused_time(&self) -> ClockDuration {
+        self.stopwatch.paused_time()
+    }
+
+    fn is_paused(&self) -> bool {
+        self.stopwatch.is_paused()
+    }
+
+    fn pause(&mut self) {
+        self.stopwatch.pause();
+    }
+
+    fn resume(&mut self) {
+        self.stopwatch.resume();
+    }
+
+    fn reset(&mut self) {
+        self.record_finished_phases();
+        self.recorded_in_run = 0;
+        self.stopwatch.reset();
+    }
+
+    fn running_segments(&self) -> Vec<TimeSegment> {
+        self.stopwatch.running_segments()
+    }
+
+    fn adjust(&mut self, amount: ClockDuration, reason: Option<String>) {
+        self.stopwatch.adjust(amount, reason);
+    }
+
+    fn adjustments(&self) -> &[Adjustment] {
+        self.stopwatch.adjustments()
+    }
+
+    fn starts_in(&self) -> Option<ClockDuration> {
+        self.stopwatch.starts_in()
+    }
+
+    fn state(&self) -> ClockState {
+        self.stopwatch.state()
+    }
+
+    /// A pomodoro moves through its phases endlessly
+    fn finished_at(&self) -> Option<UtcDateTime> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use chrono::TimeDelta;
+
+    use crate::timing::{
+        mocking_time::MockTimeImpl,
+        test_utils::{add_to_now, new_utc_moment},
+    };
+
+    use super::*;
+
+    fn set_up_pomodoro_mock(input: &str) -> (Pomodoro<MockTimeImpl>, MockTimeImpl) {
+        let time_impl = MockTimeImpl::new(new_utc_moment(input));
+        let config = PomodoroConfig::new(
+            ClockDuration::new_secs_mins_hours(None, Some(25), None),
+            ClockDuration::new_secs_mins_hours(None, Some(5), None),
+            ClockDuration::new_secs_mins_hours(None, Some(15), None),
+            NonZeroU32::new(2).unwrap(),
+        );
+        (
+            Pomodoro::new_with_impl(time_impl.clone(), config),
+            time_impl,
+        )
+    }
+
+    #[test]
+    fn move_through_phases() {
+        let (pomodoro, mut setter) = set_up_pomodoro_mock("2020-02-11 12:00:00");
+        pretty_assertions::assert_eq!(PomodoroPhase::Work, pomodoro.current_phase());
+
+        add_to_now(&mut setter, TimeDelta::minutes(27));
+        pretty_assertions::assert_eq!(PomodoroPhase::ShortBreak, pomodoro.current_phase());
+        pretty_assertions::assert_eq!("00:03:00", pomodoro.left_in_phase().to_string());
+
+        add_to_now(&mut setter, TimeDelta::minutes(30));
+        pretty_assertions::assert_eq!(PomodoroPhase::LongBreak, pomodoro.current_phase());
+        pretty_assertions::assert_eq!(2, pomodoro.completed_pomodoros());
+
+        // Next cycle starts with work again
+        add_to_now(&mut setter, TimeDelta::minutes(15));
+        pretty_assertions::assert_eq!(PomodoroPhase::Work, pomodoro.current_phase());
+        pretty_assertions::assert_eq!("00:23:00", pomodoro.left_in_phase().to_string());
+    }
+
+    #[test]
+    fn keep_phase_history_after_reset() {
+        let (mut pomodoro, mut setter) = set_up_pomodoro_mock("2020-02-11 12:00:00");
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        pomodoro.pause();
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        pomodoro.resume();
+        add_to_now(&mut setter, TimeDelta::minutes(20));
+        pomodoro.record_finished_phases();
+
+        let expected_work = PhaseRecord {
+            phase: PomodoroPhase::Work,
+            started_at: new_utc_moment("2020-02-11 12:00:00"),
+            ended_at: new_utc_moment("2020-02-11 12:35:00"),
+        };
+        let expected_break = PhaseRecord {
+            phase: PomodoroPhase::ShortBreak,
+            started_at: new_utc_moment("2020-02-11 12:35:00"),
+            ended_at: new_utc_moment("2020-02-11 12:40:00"),
+        };
+        pretty_assertions::assert_eq!(
+            vec![expected_work, expected_break],
+            pomodoro.phase_history()
+        );
+
+        pomodoro.reset();
+        add_to_now(&mut setter, TimeDelta::minutes(25));
+        pretty_assertions::assert_eq!(3, pomodoro.phase_history().len());
+        pretty_assertions::assert_eq!(2, pomodoro.completed_pomodoros());
+    }
+}
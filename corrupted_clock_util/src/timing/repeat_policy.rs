@@ -0,0 +1,26 @@
+use std::num::NonZeroU32;
+
+use serde::{Deserialize, Serialize};
+
+/// How often a count down starts over after reaching zero
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RepeatPolicy {
+    /// Count down stops after the first cycle
+    #[default]
+    Once,
+    /// Count down runs for the given number of cycles in total
+    Times(NonZeroU32),
+    /// Count down starts over every time it reaches zero
+    Forever,
+}
+
+impl RepeatPolicy {
+    /// Number of cycles in total. None if the count down repeats forever.
+    pub fn cycles(&self) -> Option<u32> {
+        match self {
+            RepeatPolicy::Once => Some(1),
+            RepeatPolicy::Times(times) => Some(times.get()),
+            RepeatPolicy::Forever => None,
+        }
+    }
+}
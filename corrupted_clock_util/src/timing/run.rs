@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ClockDuration, UtcDateTime};
+
+/// Earlier run of a clock which was archived when the clock was reset
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    started_at: UtcDateTime,
+    ended_at: UtcDateTime,
+    passed: ClockDuration,
+    paused: ClockDuration,
+}
+
+impl Run {
+    pub fn new(
+        started_at: UtcDateTime,
+        ended_at: UtcDateTime,
+        passed: ClockDuration,
+        paused: ClockDuration,
+    ) -> Self {
+        Self {
+            started_at,
+            ended_at,
+            passed,
+            paused,
+        }
+    }
+
+    pub fn started_at(&self) -> UtcDateTime {
+        self.started_at
+    }
+
+    /// Last pause before the reset or the reset itself if the clock was running
+    pub fn ended_at(&self) -> UtcDateTime {
+        self.ended_at
+    }
+
+    pub fn passed(&self) -> ClockDuration {
+        self.passed
+    }
+
+    pub fn paused(&self) -> ClockDuration {
+        self.paused
+    }
+}
+
+/// Statistics over the passed times of runs.
+/// The best run is the one with the shortest passed time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunStats {
+    count: usize,
+    average: ClockDuration,
+    best: ClockDuration,
+    worst: ClockDuration,
+}
+
+impl RunStats {
+    /// None if there are no runs
+    pub fn new(runs: &[Run]) -> Option<Self> {
+        let passed = runs.iter().map(Run::passed);
+        let (best, worst) = (passed.clone().min()?, passed.clone().max()?);
+        let total = passed.fold(ClockDuration::default(), |sum, passed| sum + passed);
+        let count = runs.len();
+        Some(Self {
+            count,
+            average: ClockDuration::from_total_nanos(total.total_nanos() / count as i128),
+            best,
+            worst,
+        })
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn average(&self) -> ClockDuration {
+        self.average
+    }
+
+    pub fn best(&self) -> ClockDuration {
+        self.best
+    }
+
+    pub fn worst(&self) -> ClockDuration {
+        self.worst
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use crate::timing::test_utils::new_utc_moment;
+
+    use super::*;
+
+    #[test]
+    fn stats_over_runs() {
+        pretty_assertions::assert_eq!(None, RunStats::new(&[]));
+
+        let at = new_utc_moment("2024-05-01 08:00:00");
+        let runs = [20, 5, 12].map(|minutes| {
+            Run::new(
+                at,
+                at,
+                ClockDuration::new_secs_mins_hours(None, Some(minutes), None),
+                ClockDuration::default(),
+            )
+        });
+        let stats = RunStats::new(&runs).unwrap();
+        pretty_assertions::assert_eq!(3, stats.count());
+        pretty_assertions::assert_eq!("00:12:20", stats.average().to_string());
+        pretty_assertions::assert_eq!("00:05:00", stats.best().to_string());
+        pretty_assertions::assert_eq!("00:20:00", stats.worst().to_string());
+    }
+}
@@ -0,0 +1,245 @@
+use thiserror::Error;
+
+use super::{InvalidDateInFuture, TimeSegment, UtcDateTime};
+
+/// Retroactive change on the running segments of a clock.
+/// Segments are referenced by their index within [`super::Timer::running_segments`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SegmentEdit {
+    /// Inserts a pause which was forgotten. The pause must lie within one running segment.
+    InsertPause {
+        from: UtcDateTime,
+        to: UtcDateTime,
+    },
+    MoveStart {
+        segment: usize,
+        to: UtcDateTime,
+    },
+    /// Moving the end of the still running segment pauses the clock at the given moment
+    MoveEnd {
+        segment: usize,
+        to: UtcDateTime,
+    },
+    Remove {
+        segment: usize,
+    },
+}
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidSegmentEdit {
+    #[error("{0}")]
+    InFuture(#[from] InvalidDateInFuture),
+    /// Contains the index of the segment. The message counts segments starting with 1.
+    #[error("There is no running segment with the number {}", .0 + 1)]
+    NoSegment(usize),
+    #[error("Pause from '{0}' to '{1}' does not lie within one running segment")]
+    PauseOutsideOfSegment(UtcDateTime, UtcDateTime),
+    #[error("Start '{0}' of a segment must be before its end '{1}'")]
+    EndBeforeStart(UtcDateTime, UtcDateTime),
+    #[error("Segment starting at '{0}' would overlap with the segment ending at '{1}'")]
+    Overlapping(UtcDateTime, UtcDateTime),
+    #[error("An alarm which ignores pauses has no running segments to edit")]
+    FixedAlarm,
+}
+
+impl SegmentEdit {
+    /// Returns the segments after this edit was applied.
+    /// The given segments are not altered if the edit is invalid.
+    pub(crate) fn apply(
+        self,
+        segments: &[TimeSegment],
+        now: UtcDateTime,
+    ) -> Result<Vec<TimeSegment>, InvalidSegmentEdit> {
+        let mut edited = segments.to_vec();
+        match self {
+            SegmentEdit::InsertPause { from, to } => {
+                ensure_not_in_future(from, now)?;
+                ensure_not_in_future(to, now)?;
+                if from >= to {
+                    return Err(InvalidSegmentEdit::EndBeforeStart(from, to));
+                }
+                let index = edited
+                    .iter()
+                    .position(|segment| segment.start() <= from && to <= segment.end_or(now))
+                    .ok_or(InvalidSegmentEdit::PauseOutsideOfSegment(from, to))?;
+                let to_split = edited.remove(index);
+                let after = match to_split.end() {
+                    Some(end) => TimeSegment::new(to, end),
+                    None => TimeSegment::new_open(to),
+                };
+                let before = TimeSegment::new(to_split.start(), from);
+                let non_empty = [before, after]
+                    .into_iter()
+                    .filter(|segment| segment.is_open() || segment.start() < segment.end_or(now));
+                edited.splice(index..index, non_empty);
+            }
+            SegmentEdit::MoveStart { segment, to } => {
+                ensure_not_in_future(to, now)?;
+                let to_move = edited
+                    .get_mut(segment)
+                    .ok_or(InvalidSegmentEdit::NoSegment(segment))?;
+                *to_move = match to_move.end() {
+                    Some(end) => TimeSegment::new(to, end),
+                    None => TimeSegment::new_open(to),
+                };
+            }
+            SegmentEdit::MoveEnd { segment, to } => {
+                ensure_not_in_future(to, now)?;
+                let to_move = edited
+                    .get_mut(segment)
+                    .ok_or(InvalidSegmentEdit::NoSegment(segment))?;
+                to_move.close(to);
+            }
+            SegmentEdit::Remove { segment } => {
+                if segment >= edited.len() {
+                    return Err(InvalidSegmentEdit::NoSegment(segment));
+                }
+                _ = edited.remove(segment);
+            }
+        }
+        validate_segments(&edited, now)?;
+        Ok(edited)
+    }
+}
+
+fn ensure_not_in_future(date: UtcDateTime, now: UtcDateTime) -> Result<(), InvalidDateInFuture> {
+    if now < date {
+        Err(InvalidDateInFuture(date))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_segments(segments: &[TimeSegment], now: UtcDateTime) -> Result<(), InvalidSegmentEdit> {
+    for segment in segments {
+        let end = segment.end_or(now);
+        ensure_not_in_future(segment.start(), now)?;
+        ensure_not_in_future(end, now)?;
+        if segment.start() >= end && !segment.is_open() {
+            return Err(InvalidSegmentEdit::EndBeforeStart(segment.start(), end));
+        }
+    }
+    for pair in segments.windows(2) {
+        let (previous, next) = (pair[0], pair[1]);
+        let previous_end = previous.end_or(now);
+        if previous.is_open() || next.start() < previous_end {
+            return Err(InvalidSegmentEdit::Overlapping(next.start(), previous_end));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod testing {
+    use chrono::TimeDelta;
+
+    use crate::timing::{
+        test_utils::{self, add_to_now, new_utc_moment},
+        Timer as _,
+    };
+
+    use super::*;
+
+    #[test]
+    fn insert_forgotten_pause() {
+        let (mut watcher, mut setter) = test_utils::new_mocked_stopwatch("2000-01-10 10:00:00");
+        add_to_now(&mut setter, TimeDelta::hours(3));
+
+        watcher
+            .edit(SegmentEdit::InsertPause {
+                from: new_utc_moment("2000-01-10 12:00:00"),
+                to: new_utc_moment("2000-01-10 12:30:00"),
+            })
+            .unwrap();
+
+        let expected = vec![
+            TimeSegment::new(
+                new_utc_moment("2000-01-10 10:00:00"),
+                new_utc_moment("2000-01-10 12:00:00"),
+            ),
+            TimeSegment::new_open(new_utc_moment("2000-01-10 12:30:00")),
+        ];
+        pretty_assertions::assert_eq!(expected, watcher.running_segments());
+        pretty_assertions::assert_eq!("02:30:00", watcher.passed().to_string());
+        pretty_assertions::assert_eq!("00:30:00", watcher.paused_time().to_string());
+        assert!(!watcher.is_paused());
+    }
+
+    #[test]
+    fn move_and_remove_segments() {
+        let (mut watcher, mut setter) = test_utils::new_mocked_stopwatch("2000-01-10 10:00:00");
+        add_to_now(&mut setter, TimeDelta::hours(1));
+        watcher.pause();
+        add_to_now(&mut setter, TimeDelta::hours(1));
+        watcher.resume();
+        add_to_now(&mut setter, TimeDelta::hours(1));
+
+        watcher
+            .edit(SegmentEdit::MoveEnd {
+                segment: 0,
+                to: new_utc_moment("2000-01-10 10:30:00"),
+            })
+            .unwrap();
+        pretty_assertions::assert_eq!("01:30:00", watcher.passed().to_string());
+
+        watcher
+            .edit(SegmentEdit::MoveEnd {
+                segment: 1,
+                to: new_utc_moment("2000-01-10 12:45:00"),
+            })
+            .unwrap();
+        assert!(watcher.is_paused());
+        pretty_assertions::assert_eq!("01:15:00", watcher.passed().to_string());
+
+        watcher.edit(SegmentEdit::Remove { segment: 0 }).unwrap();
+        pretty_assertions::assert_eq!("00:45:00", watcher.passed().to_string());
+        pretty_assertions::assert_eq!("02:15:00", watcher.paused_time().to_string());
+    }
+
+    #[test]
+    fn reject_invalid_edits() {
+        let (mut watcher, mut setter) = test_utils::new_mocked_stopwatch("2000-01-10 10:00:00");
+        add_to_now(&mut setter, TimeDelta::hours(1));
+        watcher.pause();
+        add_to_now(&mut setter, TimeDelta::hours(1));
+        watcher.resume();
+        add_to_now(&mut setter, TimeDelta::hours(1));
+        let before = watcher.running_segments();
+
+        let in_future = new_utc_moment("2000-01-10 14:00:00");
+        let actual = watcher.edit(SegmentEdit::MoveStart {
+            segment: 1,
+            to: in_future,
+        });
+        assert_eq!(
+            Err(InvalidSegmentEdit::InFuture(InvalidDateInFuture(in_future))),
+            actual
+        );
+
+        let actual = watcher.edit(SegmentEdit::MoveStart {
+            segment: 1,
+            to: new_utc_moment("2000-01-10 10:30:00"),
+        });
+        assert_eq!(
+            Err(InvalidSegmentEdit::Overlapping(
+                new_utc_moment("2000-01-10 10:30:00"),
+                new_utc_moment("2000-01-10 11:00:00"),
+            )),
+            actual
+        );
+
+        let actual = watcher.edit(SegmentEdit::InsertPause {
+            from: new_utc_moment("2000-01-10 10:30:00"),
+            to: new_utc_moment("2000-01-10 11:30:00"),
+        });
+        assert!(matches!(
+            actual,
+            Err(InvalidSegmentEdit::PauseOutsideOfSegment(_, _))
+        ));
+
+        let actual = watcher.edit(SegmentEdit::Remove { segment: 2 });
+        assert_eq!(Err(InvalidSegmentEdit::NoSegment(2)), actual);
+
+        pretty_assertions::assert_eq!(before, watcher.running_segments());
+    }
+}
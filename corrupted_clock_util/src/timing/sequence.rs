@@ -0,0 +1,355 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Adjustment, ClockDuration, ClockState, InvalidSegmentEdit, Lap, SegmentEdit, Stopwatch,
+    TimeImpl, TimeSegment, Timer, UtcDateTime, UtcTimeImpl,
+};
+
+/// Labeled part of a sequence, for example "prep" with 2 minutes
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Stage {
+    label: String,
+    duration: ClockDuration,
+}
+
+impl Stage {
+    pub fn new(label: String, duration: ClockDuration) -> Self {
+        Self { label, duration }
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn duration(&self) -> ClockDuration {
+        self.duration
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StageStatus {
+    Done,
+    Current,
+    Upcoming,
+}
+
+impl std::fmt::Display for StageStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StageStatus::Done => f.write_str("done"),
+            StageStatus::Current => f.write_str("current"),
+            StageStatus::Upcoming => f.write_str("upcoming"),
+        }
+    }
+}
+
+/// How far a sequence got within one of its stages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StageProgress<'a> {
+    stage: &'a Stage,
+    status: StageStatus,
+    started_at: Option<UtcDateTime>,
+    ended_at: Option<UtcDateTime>,
+    left: ClockDuration,
+}
+
+impl<'a> StageProgress<'a> {
+    pub fn stage(&self) -> &'a Stage {
+        self.stage
+    }
+
+    pub fn status(&self) -> StageStatus {
+        self.status
+    }
+
+    /// None if the stage has not started yet
+    pub fn started_at(&self) -> Option<UtcDateTime> {
+        self.started_at
+    }
+
+    /// None if the stage is not done yet
+    pub fn ended_at(&self) -> Option<UtcDateTime> {
+        self.ended_at
+    }
+
+    pub fn left(&self) -> ClockDuration {
+        self.left
+    }
+}
+
+/// Count down made of stages which are played one after another as one clock
+#[derive(Serialize, Deserialize)]
+pub struct Sequence<T = UtcTimeImpl>
+where
+    T: Default,
+{
+    stopwatch: Stopwatch<T>,
+    stages: Vec<Stage>,
+}
+
+impl<T> std::fmt::Debug for Sequence<T>
+where
+    T: Default,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sequence")
+            .field("stopwatch", &self.stopwatch)
+            .field("stages", &self.stages)
+            .finish()
+    }
+}
+
+impl Sequence {
+    pub fn new(stages: Vec<Stage>) -> Self {
+        Self::from_stopwatch(Stopwatch::new(), stages)
+    }
+
+    /// Sequence which starts with its first stage at the given moment
+    pub fn new_scheduled(stages: Vec<Stage>, start: UtcDateTime) -> Self {
+        Self::from_stopwatch(Stopwatch::new_scheduled(start), stages)
+    }
+}
+
+impl<T> Sequence<T>
+where
+    T: TimeImpl + Default,
+{
+    pub fn new_with_impl(time_impl: T, stages: Vec<Stage>) -> Self {
+        Self::from_stopwatch(Stopwatch::new_with_impl(time_impl), stages)
+    }
+
+    fn from_stopwatch(stopwatch: Stopwatch<T>, stages: Vec<Stage>) -> Self {
+        Self { stopwatch, stages }
+    }
+
+    pub fn stages(&self) -> &[Stage] {
+        &self.stages
+    }
+
+    /// Sum of the durations of all stages
+    pub fn total_time(&self) -> ClockDuration {
+        self.stages
+            .iter()
+            .fold(ClockDuration::default(), |sum, next| sum + next.duration)
+    }
+
+    /// Time left until the last stage is done
+    pub fn total_left(&self) -> ClockDuration {
+        (self.total_time() - self.stopwatch.passed()).max(ClockDuration::default())
+    }
+
+    /// Index starting with 0 and the stage which is currently played.
+    /// None if all stages are done.
+    pub fn current_stage(&self) -> Option<(usize, &Stage)> {
+        self.stage_progress()
+            .into_iter()
+            .enumerate()
+            .find(|(_, progress)| progress.status == StageStatus::Current)
+            .map(|(index, progress)| (index, progress.stage))
+    }
+
+    /// Time left in the current stage. Zero if all stages are done.
+    pub fn left_in_stage(&self) -> ClockDuration {
+        self.stage_progress()
+            .into_iter()
+            .find(|progress| progress.status == StageStatus::Current)
+            .map(|progress| progress.left)
+            .unwrap_or_default()
+    }
+
+    /// Progress of every stage in the order they are played
+    pub fn stage_progress(&self) -> Vec<StageProgress<'_>> {
+        let passed = self.stopwatch.passed();
+        let mut stage_start = ClockDuration::default();
+        self.stages
+            .iter()
+            .map(|stage| {
+                let start = stage_start;
+                let end = start + stage.duration;
+                stage_start = end;
+                let status = if end <= passed {
+                    StageStatus::Done
+                } else if start <= passed {
+                    StageStatus::Current
+                } else {
+                    StageStatus::Upcoming
+                };
+                let started_at = match status {
+                    StageStatus::Upcoming => None,
+                    _ => self.stopwatch.moment_at_passed(start),
+                };
+                let ended_at = match status {
+                    StageStatus::Done => self.stopwatch.moment_at_passed(end),
+                    _ => None,
+                };
+                let left = (end - passed.max(start)).max(ClockDuration::default());
+                StageProgress {
+                    stage,
+                    status,
+                    started_at,
+                    ended_at,
+                    left,
+                }
+            })
+            .collect()
+    }
+
+    pub fn laps(&self) -> &[Lap] {
+        self.stopwatch.laps()
+    }
+
+    pub fn lap(&mut self) -> Lap {
+        self.stopwatch.lap()
+    }
+
+    /// Segments after the end of the last stage are not shown and cannot be edited
+    pub fn edit(&mut self, edit: SegmentEdit) -> Result<(), InvalidSegmentEdit> {
+        let limit = self.total_time() - self.stopwatch.adjusted_by();
+        self.stopwatch.edit_up_to(edit, limit)
+    }
+}
+
+impl<T> Sequence<T>
+where
+    T: Default,
+{
+    pub fn stopwatch(&self) -> &Stopwatch<T> {
+        &self.stopwatch
+    }
+
+    pub(crate) fn mut_stopwatch(&mut self) -> &mut Stopwatch<T> {
+        &mut self.stopwatch
+    }
+}
+
+impl<T> Timer for Sequence<T>
+where
+    T: TimeImpl + Default,
+{
+    fn created_at(&self) -> UtcDateTime {
+        self.stopwatch.created_at()
+    }
+
+    fn start_moment(&self) -> UtcDateTime {
+        self.stopwatch.start_moment()
+    }
+
+    fn last_resumed_at(&self) -> Option<UtcDateTime> {
+        self.stopwatch.last_resumed_at()
+    }
+
+    fn last_paused_at(&self) -> Option<UtcDateTime> {
+        self.stopwatch.last_paused_at()
+    }
+
+    fn passed(&self) -> ClockDuration {
+        self.stopwatch.passed().min(self.total_time())
+    }
+
+    fn paused_time(&self) -> ClockDuration {
+        self.stopwatch.paused_time()
+    }
+
+    fn is_paused(&self) -> bool {
+        self.stopwatch.is_paused()
+    }
+
+    fn pause(&mut self) {
+        self.stopwatch.pause();
+    }
+
+    fn resume(&mut self) {
+        self.stopwatch.resume();
+    }
+
+    fn reset(&mut self) {
+        self.stopwatch.reset();
+    }
+
+    fn running_segments(&self) -> Vec<TimeSegment> {
+        self.stopwatch
+            .segments_up_to(self.total_time() - self.stopwatch.adjusted_by())
+    }
+
+    fn adjust(&mut self, amount: ClockDuration, reason: Option<String>) {
+        self.stopwatch.adjust(amount, reason);
+    }
+
+    fn adjustments(&self) -> &[Adjustment] {
+        self.stopwatch.adjustments()
+    }
+
+    fn starts_in(&self) -> Option<ClockDuration> {
+        self.stopwatch.starts_in()
+    }
+
+    fn state(&self) -> ClockState {
+        match self.stopwatch.state() {
+            ClockState::Scheduled => ClockState::Scheduled,
+            _ if self.finished_at().is_some() => ClockState::Finished,
+            other => other,
+        }
+    }
+
+    fn finished_at(&self) -> Option<UtcDateTime> {
+        self.stopwatch.moment_at_passed(self.total_time())
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use chrono::TimeDelta;
+
+    use crate::timing::{
+        mocking_time::MockTimeImpl,
+        test_utils::{add_to_now, new_utc_moment},
+    };
+
+    use super::*;
+
+    fn workout() -> Vec<Stage> {
+        [("prep", 2), ("run", 20), ("cooldown", 5)]
+            .map(|(label, minutes)| {
+                Stage::new(
+                    label.to_string(),
+                    ClockDuration::new_secs_mins_hours(None, Some(minutes), None),
+                )
+            })
+            .into()
+    }
+
+    #[test]
+    fn play_stages_one_after_another() {
+        let time_impl = MockTimeImpl::new(new_utc_moment("2024-05-01 08:00:00"));
+        let mut setter = time_impl.clone();
+        let mut sequence = Sequence::new_with_impl(time_impl, workout());
+        pretty_assertions::assert_eq!("00:27:00", sequence.total_left().to_string());
+
+        add_to_now(&mut setter, TimeDelta::minutes(5));
+        sequence.pause();
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        sequence.resume();
+        let (index, stage) = sequence.current_stage().unwrap();
+        pretty_assertions::assert_eq!((1, "run"), (index, stage.label()));
+        pretty_assertions::assert_eq!("00:17:00", sequence.left_in_stage().to_string());
+        pretty_assertions::assert_eq!("00:22:00", sequence.total_left().to_string());
+
+        let progress = sequence.stage_progress();
+        pretty_assertions::assert_eq!(StageStatus::Done, progress[0].status());
+        pretty_assertions::assert_eq!(
+            Some(new_utc_moment("2024-05-01 08:02:00")),
+            progress[0].ended_at()
+        );
+        pretty_assertions::assert_eq!(StageStatus::Upcoming, progress[2].status());
+        pretty_assertions::assert_eq!("00:05:00", progress[2].left().to_string());
+
+        add_to_now(&mut setter, TimeDelta::hours(1));
+        pretty_assertions::assert_eq!(None, sequence.current_stage());
+        pretty_assertions::assert_eq!(ClockState::Finished, sequence.state());
+        pretty_assertions::assert_eq!(
+            Some(new_utc_moment("2024-05-01 08:37:00")),
+            sequence.finished_at()
+        );
+        pretty_assertions::assert_eq!("00:27:00", sequence.passed().to_string());
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ClockDuration, ExclusiveMode, TimeJumpPolicy};
+
+/// Options which apply to all clocks of a clock table
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq, Eq)]
+pub struct Settings {
+    #[serde(default)]
+    exclusive_mode: ExclusiveMode,
+    #[serde(default)]
+    default_cap: Option<ClockDuration>,
+    #[serde(default)]
+    time_jump_policy: TimeJumpPolicy,
+}
+
+impl Settings {
+    pub fn exclusive_mode(&self) -> ExclusiveMode {
+        self.exclusive_mode
+    }
+
+    pub fn set_exclusive_mode(&mut self, mode: ExclusiveMode) {
+        self.exclusive_mode = mode;
+    }
+
+    /// Cap of new stopwatches which are created without a cap
+    pub fn default_cap(&self) -> Option<ClockDuration> {
+        self.default_cap
+    }
+
+    pub fn set_default_cap(&mut self, cap: Option<ClockDuration>) {
+        self.default_cap = cap;
+    }
+
+    pub fn time_jump_policy(&self) -> TimeJumpPolicy {
+        self.time_jump_policy
+    }
+
+    /// Only via the clock table since its clocks have to know the policy too
+    pub(crate) fn set_time_jump_policy(&mut self, policy: TimeJumpPolicy) {
+        self.time_jump_policy = policy;
+    }
+}
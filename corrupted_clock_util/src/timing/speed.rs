@@ -0,0 +1,231 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::{ClockDuration, UtcDateTime};
+
+/// Most decimal places accepted for a speed. Finer factors can be given as fraction.
+const MAX_DECIMAL_PLACES: u32 = 9;
+
+/// Factor by which the passed time of a clock grows compared to the real time.
+/// Kept as an exact fraction so totals do not drift by rounding.
+///
+/// A speed of 10 lets 10 minutes pass on the clock per real minute.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct Speed {
+    numerator: u64,
+    denominator: u64,
+}
+
+#[derive(Debug, Error, PartialEq, Eq, Clone, Copy)]
+pub enum InvalidSpeed {
+    #[error("Speed must be a number like 10 or 0.5 or a fraction like 1/3")]
+    InvalidFormat,
+    #[error("Speed must be greater than zero")]
+    NotPositive,
+    #[error("Speed must not have more than 9 decimal places")]
+    TooPrecise,
+}
+
+impl Default for Speed {
+    fn default() -> Self {
+        Self::REAL_TIME
+    }
+}
+
+impl Speed {
+    /// Clock runs as fast as the real time
+    pub const REAL_TIME: Self = Self {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    pub fn new(numerator: u64, denominator: u64) -> Result<Self, InvalidSpeed> {
+        if numerator == 0 || denominator == 0 {
+            return Err(InvalidSpeed::NotPositive);
+        }
+        let divisor = gcd(numerator, denominator);
+        Ok(Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        })
+    }
+
+    pub fn numerator(&self) -> u64 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> u64 {
+        self.denominator
+    }
+
+    /// Clock time which passes within the given real time, rounded towards zero
+    pub fn scale(&self, real: ClockDuration) -> ClockDuration {
+        ClockDuration::from_total_nanos(
+            real.total_nanos() * i128::from(self.numerator) / i128::from(self.denominator),
+        )
+    }
+
+    /// Real time in which the given clock time passes, rounded up
+    /// so the clock time has fully passed afterwards
+    pub fn unscale(&self, clock_time: ClockDuration) -> ClockDuration {
+        let (numerator, denominator) = (i128::from(self.numerator), i128::from(self.denominator));
+        let nanos = clock_time.total_nanos() * denominator;
+        let real = if nanos > 0 {
+            (nanos + numerator - 1) / numerator
+        } else {
+            nanos / numerator
+        };
+        ClockDuration::from_total_nanos(real)
+    }
+}
+
+impl FromStr for Speed {
+    type Err = InvalidSpeed;
+
+    /// Accepts a decimal number like "10" or "0.25" or a fraction like "1/3".
+    /// A trailing "x" is ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn parse_digits(digits: &str) -> Result<u64, InvalidSpeed> {
+            if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+                return Err(InvalidSpeed::InvalidFormat);
+            }
+            digits.parse().map_err(|_| InvalidSpeed::InvalidFormat)
+        }
+
+        let s = s.trim();
+        let s = s.strip_suffix(['x', 'X']).unwrap_or(s);
+        if let Some((numerator, denominator)) = s.split_once('/') {
+            return Self::new(parse_digits(numerator)?, parse_digits(denominator)?);
+        }
+        let (whole, decimals) = s.split_once('.').unwrap_or((s, ""));
+        let places = decimals.len() as u32;
+        if places > MAX_DECIMAL_PLACES {
+            return Err(InvalidSpeed::TooPrecise);
+        }
+        let scale = 10u64.pow(places);
+        let numerator = parse_digits(whole)?
+            .checked_mul(scale)
+            .ok_or(InvalidSpeed::InvalidFormat)?;
+        let decimals = if decimals.is_empty() {
+            0
+        } else {
+            parse_digits(decimals)?
+        };
+        Self::new(numerator + decimals, scale)
+    }
+}
+
+impl std::fmt::Display for Speed {
+    /// Decimal number if it is exact, otherwise a fraction
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let places = (0..=MAX_DECIMAL_PLACES)
+            .find(|places| 10u64.pow(*places) % self.denominator == 0)
+            .map(|places| places as usize);
+        match places {
+            Some(0) => write!(f, "{}x", self.numerator),
+            Some(places) => {
+                let scale = 10u64.pow(places as u32);
+                let scaled = u128::from(self.numerator) * u128::from(scale / self.denominator);
+                let scale = u128::from(scale);
+                write!(f, "{}.{:0places$}x", scaled / scale, scaled % scale)
+            }
+            None => write!(f, "{}/{}x", self.numerator, self.denominator),
+        }
+    }
+}
+
+/// Speed of a clock from the given moment on until the next change
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct RateChange {
+    changed_at: UtcDateTime,
+    speed: Speed,
+}
+
+impl RateChange {
+    pub fn new(changed_at: UtcDateTime, speed: Speed) -> Self {
+        Self { changed_at, speed }
+    }
+
+    pub fn changed_at(&self) -> UtcDateTime {
+        self.changed_at
+    }
+
+    pub fn speed(&self) -> Speed {
+        self.speed
+    }
+
+    /// Splits the real time between `start` and `end` into parts with the same speed.
+    /// The changes have to be ordered by their moments. Before the first change a clock runs
+    /// in real time.
+    pub(crate) fn split(
+        changes: &[RateChange],
+        start: UtcDateTime,
+        end: UtcDateTime,
+    ) -> Vec<(Speed, UtcDateTime, UtcDateTime)> {
+        let mut speed = changes
+            .iter()
+            .take_while(|change| change.changed_at <= start)
+            .last()
+            .map_or(Speed::REAL_TIME, |change| change.speed);
+        let mut part_start = start;
+        let mut parts = Vec::new();
+        for change in changes
+            .iter()
+            .filter(|change| start < change.changed_at && change.changed_at < end)
+        {
+            parts.push((speed, part_start, change.changed_at));
+            part_start = change.changed_at;
+            speed = change.speed;
+        }
+        parts.push((speed, part_start, end));
+        parts
+    }
+}
+
+fn gcd(mut left: u64, mut right: u64) -> u64 {
+    while right != 0 {
+        (left, right) = (right, left % right);
+    }
+    left
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn parse_and_display_speed() {
+        fn assert_case(input: &str, expected: Result<&str, InvalidSpeed>) {
+            let actual = input.parse::<Speed>().map(|speed| speed.to_string());
+            assert_eq!(
+                expected.map(ToOwned::to_owned),
+                actual,
+                "Given input: {}",
+                input
+            );
+        }
+        assert_case("10", Ok("10x"));
+        assert_case("10x", Ok("10x"));
+        assert_case("0.5", Ok("0.5x"));
+        assert_case("1.250", Ok("1.25x"));
+        assert_case("2/4", Ok("0.5x"));
+        assert_case("1/3", Ok("1/3x"));
+        assert_case("0", Err(InvalidSpeed::NotPositive));
+        assert_case("0.0", Err(InvalidSpeed::NotPositive));
+        assert_case("1/0", Err(InvalidSpeed::NotPositive));
+        assert_case("-2", Err(InvalidSpeed::InvalidFormat));
+        assert_case("fast", Err(InvalidSpeed::InvalidFormat));
+        assert_case("0.0000000001", Err(InvalidSpeed::TooPrecise));
+    }
+
+    #[test]
+    fn scale_exactly() {
+        let speed: Speed = "1/3".parse().unwrap();
+        let real = ClockDuration::new_secs_mins_hours(None, Some(10), None);
+        let scaled = speed.scale(real);
+        pretty_assertions::assert_eq!("00:03:20", scaled.to_string());
+        pretty_assertions::assert_eq!(real, speed.unscale(scaled));
+    }
+}
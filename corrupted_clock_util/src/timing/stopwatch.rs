@@ -1,45 +1,123 @@
+mod persisted_state;
+
+use std::borrow::Cow;
+
 use serde::{Deserialize, Serialize};
 
+use self::persisted_state::PersistedStopwatch;
+
 use super::{
-    ChronoDuration, ClockDuration, InvalidDateInFuture, TimeImpl, Timer, UtcDateTime, UtcTimeImpl,
+    Adjustment, ChronoDuration, ClockDuration, ClockState, InvalidDateInFuture, InvalidSegmentEdit,
+    Lap, RateChange, Run, SegmentEdit, Speed, TimeImpl, TimeJump, TimeJumpPolicy, TimeSegment,
+    Timer, Tz, UtcDateTime, UtcTimeImpl,
 };
 
 #[derive(Serialize, Deserialize)]
+#[serde(from = "PersistedStopwatch")]
 pub struct Stopwatch<T = UtcTimeImpl>
 where
     T: Default,
 {
     created_at: UtcDateTime,
     start_moment: UtcDateTime,
-    last_paused_at: Option<UtcDateTime>,
-    last_resume_moment: Option<UtcDateTime>,
-    is_paused: bool,
-    paused_time: ClockDuration,
-    passed_time_between_pauses: ClockDuration,
+    segments: Vec<TimeSegment>,
+    laps: Vec<Lap>,
+    adjustments: Vec<Adjustment>,
+    /// Planned time budget. The stopwatch keeps running past it.
+    goal: Option<ClockDuration>,
+    group: Option<String>,
+    time_zone: Option<Tz>,
+    /// Speeds of the passed time ordered by the moments they were set at
+    rate_changes: Vec<RateChange>,
+    /// Passed time at which the stopwatch stops on its own
+    cap: Option<ClockDuration>,
+    auto_stopped_at: Option<UtcDateTime>,
+    /// Latest moment at which the stopwatch was evaluated before it was saved
+    last_seen_at: Option<UtcDateTime>,
+    /// Corrections for system time which went backwards
+    time_jumps: Vec<TimeJump>,
+    /// Earlier runs kept by resets
+    runs: Vec<Run>,
+    #[serde(skip)]
+    jump_policy: TimeJumpPolicy,
     #[serde(skip)]
     time_impl: T,
 }
 
+impl Default for Stopwatch<UtcTimeImpl> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Stopwatch<UtcTimeImpl> {
     pub fn new() -> Self {
-        Self::new_with_impl(UtcTimeImpl::default())
+        Self::new_with_impl(UtcTimeImpl)
     }
 
     pub fn new_with_start_date(date: UtcDateTime) -> Result<Stopwatch, InvalidDateInFuture> {
         Self::new_with_impl_and_start_date(UtcTimeImpl, date)
     }
+
+    /// See [`Stopwatch::new_with_impl_and_scheduled_start`]
+    pub fn new_scheduled(start: UtcDateTime) -> Stopwatch {
+        Self::new_with_impl_and_scheduled_start(UtcTimeImpl, start)
+    }
 }
 
 impl<T> Stopwatch<T>
 where
-    T: TimeImpl + Default,
+    T: Default,
 {
-    fn resume_moment(&self) -> UtcDateTime {
-        self.last_resumed_at().unwrap_or(self.start_moment)
+    /// The stopwatch itself, like the stopwatch inside of every other kind of clock
+    pub fn stopwatch(&self) -> &Self {
+        self
+    }
+
+    pub(crate) fn mut_stopwatch(&mut self) -> &mut Self {
+        self
+    }
+
+    pub fn time_jumps(&self) -> &[TimeJump] {
+        &self.time_jumps
+    }
+
+    pub fn set_jump_policy(&mut self, policy: TimeJumpPolicy) {
+        self.jump_policy = policy;
+    }
+
+    pub fn rate_changes(&self) -> &[RateChange] {
+        &self.rate_changes
+    }
+
+    /// Earlier runs which were archived by a reset, ordered from the oldest to the newest
+    pub fn runs(&self) -> &[Run] {
+        &self.runs
+    }
+
+    /// Clocks of the same group exclude each other if the exclusive mode is scoped by group
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    pub fn set_group(&mut self, group: Option<String>) {
+        self.group = group;
+    }
+
+    /// IANA time zone in which the clock is used. Dates of the clock are shown in it.
+    pub fn time_zone(&self) -> Option<Tz> {
+        self.time_zone
+    }
+
+    pub fn set_time_zone(&mut self, zone: Option<Tz>) {
+        self.time_zone = zone;
     }
 
-    fn pause_moment(&self) -> UtcDateTime {
-        self.last_paused_at().unwrap_or(self.start_moment)
+    /// Factor by which the passed time grows compared to the real time
+    pub fn speed(&self) -> Speed {
+        self.rate_changes
+            .last()
+            .map_or(Speed::REAL_TIME, RateChange::speed)
     }
 }
 
@@ -50,70 +128,124 @@ where
     fn start_moment(&self) -> UtcDateTime {
         self.start_moment
     }
+
     fn passed(&self) -> ClockDuration {
-        if self.is_paused() {
-            self.passed_time_between_pauses
-        } else {
-            let now = self.time_impl.now();
-            let delta_and_last_resumed = now - self.resume_moment();
-            let delta = delta_and_last_resumed + self.passed_time_between_pauses.into();
-            delta.into()
+        if self.is_scheduled() {
+            return ClockDuration::default();
+        }
+        let passed = self.uncapped_passed();
+        match self.cap {
+            Some(cap) => passed.min(cap.max(ClockDuration::default())),
+            None => passed,
         }
     }
 
+    /// Pausing a scheduled stopwatch cancels its start. It has to be resumed to run.
     fn pause(&mut self) {
-        if self.is_paused() {
+        self.apply_cap();
+        let now = self.now();
+        if self.is_scheduled() {
+            self.start_moment = now;
+            self.segments.clear();
             return;
         }
-        self.is_paused = true;
-        let now = self.time_impl.now();
-        self.last_paused_at = Some(now);
-        let to_add = now - self.resume_moment();
-        self.passed_time_between_pauses =
-            (ChronoDuration::from(self.passed_time_between_pauses) + to_add).into();
+        if let Some(running) = self.segments.last_mut().filter(|last| last.is_open()) {
+            running.close(now);
+        }
     }
 
+    /// Has no effect on a stopwatch which has reached its cap, see [`Stopwatch::reached_cap`]
     fn resume(&mut self) {
-        if !self.is_paused() {
+        self.apply_cap();
+        if !self.is_paused() || self.reached_cap() {
             return;
         }
-        self.is_paused = false;
-        let now = self.time_impl.now();
-        self.paused_time = self.paused_time + (now - self.resume_moment()).into();
-        self.last_resume_moment = Some(now);
+        let now = self.now();
+        self.auto_stopped_at = None;
+        self.segments.push(TimeSegment::new_open(now));
     }
 
     fn reset(&mut self) {
-        self.start_moment = self.time_impl.now();
-        self.is_paused = false;
-        self.last_resume_moment = None;
-        self.last_paused_at = None;
-        self.passed_time_between_pauses = Default::default();
-        self.paused_time = Default::default();
+        let now = self.now();
+        self.start_moment = now;
+        self.segments = vec![TimeSegment::new_open(now)];
+        self.laps.clear();
+        self.adjustments.clear();
+        self.auto_stopped_at = None;
+        let speed = self.speed();
+        self.rate_changes.clear();
+        if speed != Speed::REAL_TIME {
+            self.rate_changes.push(RateChange::new(now, speed));
+        }
     }
 
     fn is_paused(&self) -> bool {
-        self.is_paused
+        self.capped_segments()
+            .last()
+            .is_none_or(|last| !last.is_open())
     }
 
     fn created_at(&self) -> UtcDateTime {
-        self.start_moment
+        self.created_at
     }
 
     fn paused_time(&self) -> ClockDuration {
-        let current_pause_delta = if self.is_paused() {
-            (self.time_impl.now() - self.pause_moment()).into()
-        } else {
-            Default::default()
-        };
-        self.paused_time + current_pause_delta
+        let now = self.now();
+        if now < self.start_moment {
+            return ClockDuration::default();
+        }
+        let until_now: ClockDuration = (now - self.start_moment).into();
+        until_now - TimeSegment::sum_durations(self.capped_segments().iter(), now)
     }
 
     fn last_paused_at(&self) -> Option<UtcDateTime> {
-        self.last_paused_at
+        self.capped_segments()
+            .iter()
+            .rev()
+            .find_map(|segment| segment.end())
     }
+
     fn last_resumed_at(&self) -> Option<UtcDateTime> {
-        self.last_resume_moment
+        match self.capped_segments().as_ref() {
+            [_, .., last] => Some(last.start()),
+            _ => None,
+        }
+    }
+
+    fn running_segments(&self) -> Vec<TimeSegment> {
+        if self.is_scheduled() {
+            return Vec::new();
+        }
+        self.capped_segments().into_owned()
+    }
+
+    fn starts_in(&self) -> Option<ClockDuration> {
+        let now = self.now();
+        (now < self.start_moment).then(|| (self.start_moment - now).into())
+    }
+
+    fn state(&self) -> ClockState {
+        if self.is_scheduled() {
+            ClockState::Scheduled
+        } else if self.is_paused() {
+            ClockState::Paused
+        } else {
+            ClockState::Running
+        }
+    }
+
+    fn finished_at(&self) -> Option<UtcDateTime> {
+        None
+    }
+
+    fn adjust(&mut self, amount: ClockDuration, reason: Option<String>) {
+        self.apply_cap();
+        let now = self.now();
+        self.adjustments.push(Adjustment::new(now, amount, reason));
+    }
+
+    fn adjustments(&self) -> &[Adjustment] {
+        &self.adjustments
     }
 }
 
@@ -131,18 +263,376 @@ where
         date: UtcDateTime,
     ) -> Result<Self, InvalidDateInFuture> {
         super::validate_if_date_is_not_in_future(&time_impl, date)?;
-        let created_at = date;
-        let paused_time = Default::default();
-        Ok(Self {
+        Ok(Self::new_with_impl_and_scheduled_start(time_impl, date))
+    }
+
+    /// Stopwatch which starts running at the given moment.
+    /// Until then it is scheduled and its passed time is zero.
+    /// A start in the past behaves like a start date.
+    pub fn new_with_impl_and_scheduled_start(time_impl: T, start: UtcDateTime) -> Self {
+        let created_at = start.min(time_impl.now());
+        Self {
             created_at,
-            start_moment: created_at,
-            paused_time,
-            passed_time_between_pauses: paused_time,
+            start_moment: start,
+            segments: vec![TimeSegment::new_open(start)],
             time_impl,
-            is_paused: false,
-            last_paused_at: None,
-            last_resume_moment: None,
-        })
+            laps: Vec::new(),
+            adjustments: Vec::new(),
+            goal: None,
+            group: None,
+            time_zone: None,
+            rate_changes: Vec::new(),
+            cap: None,
+            auto_stopped_at: None,
+            last_seen_at: None,
+            time_jumps: Vec::new(),
+            runs: Vec::new(),
+            jump_policy: TimeJumpPolicy::default(),
+        }
+    }
+
+    /// Not started yet since its scheduled start lies in the future
+    pub fn is_scheduled(&self) -> bool {
+        self.starts_in().is_some()
+    }
+
+    /// Time spent in running segments without any adjustments
+    pub fn running_time(&self) -> ClockDuration {
+        TimeSegment::sum_durations(self.capped_segments().iter(), self.now())
+    }
+
+    pub fn cap(&self) -> Option<ClockDuration> {
+        self.cap
+    }
+
+    /// Passed time reached the cap, so the stopwatch cannot be resumed until the cap is raised
+    pub fn reached_cap(&self) -> bool {
+        self.cap.is_some_and(|cap| self.passed() >= cap)
+    }
+
+    /// Sets or removes the passed time at which the stopwatch stops on its own.
+    /// A stopwatch already past a new cap behaves as if it was paused when it reached the cap.
+    pub fn set_cap(&mut self, cap: Option<ClockDuration>) {
+        self.cap = cap;
+    }
+
+    /// Moment at which the stopwatch was stopped by reaching its cap.
+    /// None if it was resumed since then.
+    pub fn auto_stopped_at(&self) -> Option<UtcDateTime> {
+        self.reached_cap_at().or(self.auto_stopped_at)
+    }
+
+    /// Cuts the running segments off at the moment the cap was reached.
+    /// Afterwards the stopwatch stays paused even if the cap is raised.
+    pub fn apply_cap(&mut self) {
+        if let Some(at) = self.reached_cap_at() {
+            self.segments = self.capped_segments().into_owned();
+            self.auto_stopped_at = Some(at);
+        }
+    }
+
+    fn uncapped_passed(&self) -> ClockDuration {
+        let now = self.now();
+        let passed = self
+            .segments
+            .iter()
+            .fold(ClockDuration::default(), |sum, segment| {
+                sum + self.scaled_duration(segment, now)
+            })
+            + self.adjusted_by();
+        passed.max(ClockDuration::default())
+    }
+
+    /// Running time of the segment scaled by the speeds in effect while it was running
+    fn scaled_duration(&self, segment: &TimeSegment, now: UtcDateTime) -> ClockDuration {
+        RateChange::split(&self.rate_changes, segment.start(), segment.end_or(now))
+            .into_iter()
+            .fold(ClockDuration::default(), |sum, (speed, start, end)| {
+                sum + speed.scale((end - start).into())
+            })
+    }
+
+    /// Moment at which the given scaled running time has passed within the segment.
+    /// The end of the segment if it is shorter.
+    fn moment_in_segment(
+        &self,
+        segment: &TimeSegment,
+        now: UtcDateTime,
+        passed: ClockDuration,
+    ) -> UtcDateTime {
+        let end = segment.end_or(now);
+        let mut left = passed;
+        for (speed, start, part_end) in RateChange::split(&self.rate_changes, segment.start(), end)
+        {
+            let scaled = speed.scale((part_end - start).into());
+            if left <= scaled {
+                return (start + ChronoDuration::from(speed.unscale(left))).min(part_end);
+            }
+            left = left - scaled;
+        }
+        end
+    }
+
+    /// Moment at which the passed time reached the cap, while the running segments go past it
+    fn reached_cap_at(&self) -> Option<UtcDateTime> {
+        let cap = self.cap?;
+        if self.is_scheduled() || self.uncapped_passed() <= cap {
+            return None;
+        }
+        self.moment_at_passed(cap)
+    }
+
+    /// Running segments as if the stopwatch was paused at the moment it reached its cap
+    fn capped_segments(&self) -> Cow<'_, [TimeSegment]> {
+        match (self.cap, self.reached_cap_at()) {
+            (Some(cap), Some(_)) => Cow::Owned(self.segments_up_to(cap - self.adjusted_by())),
+            _ => Cow::Borrowed(&self.segments),
+        }
+    }
+
+    /// Changes the speed from now on. Time passed so far keeps the speeds it passed with.
+    pub fn set_speed(&mut self, speed: Speed) {
+        self.apply_cap();
+        if self.speed() == speed {
+            return;
+        }
+        let now = self.now();
+        self.rate_changes.retain(|change| change.changed_at() < now);
+        self.rate_changes.push(RateChange::new(now, speed));
+    }
+
+    /// Adds the run so far to the run history. A scheduled stopwatch has no run to add.
+    /// The clock which owns the stopwatch provides its own passed time and the moment it finished.
+    /// Without a finish, the run ends at the last pause or now if the stopwatch is running.
+    pub(crate) fn archive_run(&mut self, passed: ClockDuration, finished_at: Option<UtcDateTime>) {
+        if self.is_scheduled() {
+            return;
+        }
+        let now = self.now();
+        let ended_at = match finished_at {
+            Some(finished) => finished.min(now),
+            None if self.is_paused() => self.last_paused_at().unwrap_or(self.start_moment),
+            None => now,
+        };
+        let running = self
+            .capped_segments()
+            .iter()
+            .filter(|segment| segment.start() < ended_at)
+            .fold(ClockDuration::default(), |sum, segment| {
+                sum + (segment.end_or(now).min(ended_at) - segment.start()).into()
+            });
+        let paused = ClockDuration::from(ended_at - self.start_moment) - running;
+        self.runs
+            .push(Run::new(self.start_moment, ended_at, passed, paused));
+    }
+
+    /// Passed time within the interval from `from` up to `to`.
+    /// Contains the adjustments recorded within the interval.
+    pub fn passed_between(&self, from: UtcDateTime, to: UtcDateTime) -> ClockDuration {
+        let now = self.now();
+        let to = to.min(now);
+        let running =
+            self.capped_segments()
+                .iter()
+                .fold(ClockDuration::default(), |sum, segment| {
+                    let (start, end) = (segment.start().max(from), segment.end_or(now).min(to));
+                    if start < end {
+                        sum + self.scaled_duration(&TimeSegment::new(start, end), now)
+                    } else {
+                        sum
+                    }
+                });
+        let adjusted = Adjustment::sum_amounts(
+            self.adjustments
+                .iter()
+                .filter(|adjustment| (from..to).contains(&adjustment.recorded_at())),
+        );
+        running + adjusted
+    }
+
+    /// Sum of all adjustments made to the passed time
+    pub fn adjusted_by(&self) -> ClockDuration {
+        Adjustment::sum_amounts(&self.adjustments)
+    }
+
+    pub fn laps(&self) -> &[Lap] {
+        &self.laps
+    }
+
+    pub fn goal(&self) -> Option<ClockDuration> {
+        self.goal
+    }
+
+    /// Sets or removes the planned time budget
+    pub fn set_goal(&mut self, goal: Option<ClockDuration>) {
+        self.goal = goal;
+    }
+
+    /// Passed time as percentage of the goal, rounded down. Exceeds 100 past the goal.
+    pub fn goal_percent(&self) -> Option<u64> {
+        let goal = self.goal?.total_nanos();
+        if goal <= 0 {
+            return Some(100);
+        }
+        let percent = self.passed().total_nanos() * 100 / goal;
+        Some(u64::try_from(percent).unwrap_or(u64::MAX))
+    }
+
+    /// Time still missing to reach the goal. Zero once the goal is reached.
+    pub fn left_to_goal(&self) -> Option<ClockDuration> {
+        self.goal
+            .map(|goal| (goal - self.passed()).max(ClockDuration::default()))
+    }
+
+    /// How far the passed time overshoots the goal. Zero until the goal is reached.
+    pub fn over_goal(&self) -> Option<ClockDuration> {
+        self.goal
+            .map(|goal| (self.passed() - goal).max(ClockDuration::default()))
+    }
+
+    /// Records a lap at the current moment without pausing the stopwatch.
+    pub fn lap(&mut self) -> Lap {
+        let total = self.passed();
+        let previous_total = self
+            .laps
+            .last()
+            .map(|previous| previous.total())
+            .unwrap_or_default();
+        let lap = Lap::new(self.now(), total - previous_total, total);
+        self.laps.push(lap);
+        lap
+    }
+
+    /// Retroactively changes the running segments.
+    /// Passed and paused time are derived from the segments and change accordingly.
+    pub fn edit(&mut self, edit: SegmentEdit) -> Result<(), InvalidSegmentEdit> {
+        self.apply_cap();
+        let now = self.now();
+        self.segments = edit.apply(&self.segments, now)?;
+        if let Some(first) = self.segments.first() {
+            self.start_moment = self.start_moment.min(first.start());
+        }
+        Ok(())
+    }
+
+    /// Edit which only reaches the segments up to the given passed time.
+    /// Segment numbers refer to [`Self::segments_up_to`] with this limit.
+    /// Later segments do not count towards the passed time and are not editable.
+    pub(crate) fn edit_up_to(
+        &mut self,
+        edit: SegmentEdit,
+        limit: ClockDuration,
+    ) -> Result<(), InvalidSegmentEdit> {
+        let shown = self.segments_up_to(limit);
+        match edit {
+            SegmentEdit::MoveStart { segment, .. }
+            | SegmentEdit::MoveEnd { segment, .. }
+            | SegmentEdit::Remove { segment }
+                if segment >= shown.len() =>
+            {
+                Err(InvalidSegmentEdit::NoSegment(segment))
+            }
+            SegmentEdit::InsertPause { from, to }
+                if !shown
+                    .iter()
+                    .any(|segment| segment.start() <= from && to <= segment.end_or(self.now())) =>
+            {
+                Err(InvalidSegmentEdit::PauseOutsideOfSegment(from, to))
+            }
+            _ => self.edit(edit),
+        }
+    }
+
+    /// Moment at which the passed time reached the given duration.
+    /// None if the stopwatch has not reached this duration yet.
+    pub fn moment_at_passed(&self, passed: ClockDuration) -> Option<UtcDateTime> {
+        let now = self.now();
+        let mut left = passed - self.adjusted_by();
+        for segment in self.segments.iter() {
+            let duration = self.scaled_duration(segment, now);
+            if left <= duration {
+                let left = left.max(ClockDuration::default());
+                return Some(self.moment_in_segment(segment, now, left));
+            }
+            left = left - duration;
+        }
+        None
+    }
+
+    /// Latest moment this stopwatch has recorded so far
+    pub fn last_recorded_at(&self) -> UtcDateTime {
+        let segment_ends = self.segments.iter().filter_map(TimeSegment::end);
+        let laps = self.laps.iter().map(Lap::recorded_at);
+        let adjustments = self.adjustments.iter().map(Adjustment::recorded_at);
+        let rate_changes = self.rate_changes.iter().map(RateChange::changed_at);
+        std::iter::once(self.created_at)
+            .chain(self.last_seen_at)
+            .chain(self.auto_stopped_at)
+            .chain(segment_ends)
+            .chain(laps)
+            .chain(adjustments)
+            .chain(rate_changes)
+            .max()
+            .unwrap_or(self.created_at)
+    }
+
+    /// Jump of the system time behind the last recorded moment, which is not corrected yet.
+    /// Detected regardless of the policy.
+    pub fn time_jump(&self) -> Option<TimeJump> {
+        let now = self.corrected_now();
+        let last_recorded_at = self.last_recorded_at();
+        (now < last_recorded_at).then(|| TimeJump::new(now, (last_recorded_at - now).into()))
+    }
+
+    /// Remembers the current moment as seen. With the policy "record" a pending time jump
+    /// is recorded as correction before.
+    pub fn record_evaluation(&mut self) {
+        if self.jump_policy == TimeJumpPolicy::Record {
+            if let Some(jump) = self.time_jump() {
+                self.time_jumps.push(jump);
+            }
+        }
+        let now = self.now();
+        self.last_seen_at = Some(self.last_seen_at.map_or(now, |seen| seen.max(now)));
+    }
+
+    /// Current moment according to the time implementation with all recorded corrections
+    fn corrected_now(&self) -> UtcDateTime {
+        self.time_impl.now() + ChronoDuration::from(TimeJump::sum_corrections(&self.time_jumps))
+    }
+
+    /// Moment at which this stopwatch is evaluated.
+    /// Does not lie before the last recorded moment unless the policy is "warn".
+    pub(crate) fn now(&self) -> UtcDateTime {
+        let now = self.corrected_now();
+        match self.jump_policy {
+            TimeJumpPolicy::Warn => now,
+            TimeJumpPolicy::Clamp | TimeJumpPolicy::Record => now.max(self.last_recorded_at()),
+        }
+    }
+
+    /// Running segments cut off as soon as the summed up scaled running time reaches `limit`
+    pub(crate) fn segments_up_to(&self, limit: ClockDuration) -> Vec<TimeSegment> {
+        if self.is_scheduled() {
+            return Vec::new();
+        }
+        let now = self.now();
+        let mut left = limit;
+        let mut clipped = Vec::with_capacity(self.segments.len());
+        for segment in self.segments.iter() {
+            if left <= ClockDuration::default() {
+                break;
+            }
+            let duration = self.scaled_duration(segment, now);
+            if duration > left {
+                let end = self.moment_in_segment(segment, now, left);
+                clipped.push(TimeSegment::new(segment.start(), end));
+                break;
+            }
+            left = left - duration;
+            clipped.push(*segment);
+        }
+        clipped
     }
 }
 
@@ -153,7 +643,7 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Stopwatch")
             .field("Started", &self.start_moment)
-            .field("Paused", &self.passed_time_between_pauses)
+            .field("Segments", &self.segments)
             .finish()
     }
 }
@@ -198,6 +688,60 @@ mod testing {
         assert_passed(&watcher, "00:20:00");
     }
 
+    #[test]
+    fn reset_archives_run() {
+        let (mut watcher, mut setter) = test_utils::new_mocked_stopwatch("2000-01-10 10:00:00");
+
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        watcher.pause();
+        add_to_now(&mut setter, TimeDelta::minutes(5));
+        watcher.resume();
+        add_to_now(&mut setter, TimeDelta::minutes(20));
+        watcher.pause();
+        add_to_now(&mut setter, TimeDelta::minutes(30));
+        watcher.archive_run(watcher.passed(), watcher.finished_at());
+        watcher.reset();
+        assert_passed(&watcher, "00:00:00");
+
+        // Plain reset discards the run but keeps the history
+        add_to_now(&mut setter, TimeDelta::minutes(15));
+        watcher.reset();
+        add_to_now(&mut setter, TimeDelta::minutes(12));
+        watcher.archive_run(watcher.passed(), watcher.finished_at());
+        watcher.reset();
+
+        let runs: Vec<(UtcDateTime, UtcDateTime, String, String)> = watcher
+            .runs()
+            .iter()
+            .map(|run| {
+                (
+                    run.started_at(),
+                    run.ended_at(),
+                    run.passed().to_string(),
+                    run.paused().to_string(),
+                )
+            })
+            .collect();
+        let at = test_utils::new_utc_moment;
+        pretty_assertions::assert_eq!(
+            vec![
+                (
+                    at("2000-01-10 10:00:00"),
+                    at("2000-01-10 10:35:00"),
+                    "00:30:00".to_string(),
+                    "00:05:00".to_string()
+                ),
+                (
+                    at("2000-01-10 11:20:00"),
+                    at("2000-01-10 11:32:00"),
+                    "00:12:00".to_string(),
+                    "00:00:00".to_string()
+                ),
+            ],
+            runs
+        );
+    }
+
     #[test]
     fn pause_and_resume_watch() {
         let (mut watcher, mut setter) = test_utils::new_mocked_stopwatch("2000-01-10 10:00:00");
@@ -226,4 +770,316 @@ mod testing {
         add_to_now(&mut setter, TimeDelta::hours(3));
         assert_passed(&watcher, "04:10:51");
     }
+
+    #[test]
+    fn record_laps() {
+        let (mut watcher, mut setter) = test_utils::new_mocked_stopwatch("2000-01-10 10:00:00");
+
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        let first = watcher.lap();
+        add_to_now(&mut setter, TimeDelta::minutes(5));
+        watcher.pause();
+        add_to_now(&mut setter, TimeDelta::hours(1));
+        watcher.resume();
+        add_to_now(&mut setter, TimeDelta::minutes(2));
+        let second = watcher.lap();
+
+        pretty_assertions::assert_eq!("00:10:00", first.split().to_string());
+        pretty_assertions::assert_eq!("00:10:00", first.total().to_string());
+        pretty_assertions::assert_eq!("00:07:00", second.split().to_string());
+        pretty_assertions::assert_eq!("00:17:00", second.total().to_string());
+        pretty_assertions::assert_eq!(setter.now(), second.recorded_at());
+        pretty_assertions::assert_eq!(&[first, second], watcher.laps());
+        // Lap recording must not pause the stopwatch
+        assert!(!watcher.is_paused());
+
+        watcher.reset();
+        assert!(watcher.laps().is_empty());
+    }
+
+    #[test]
+    fn keep_running_segments() {
+        let (mut watcher, mut setter) = test_utils::new_mocked_stopwatch("2000-01-10 10:00:00");
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        watcher.pause();
+        add_to_now(&mut setter, TimeDelta::minutes(20));
+        watcher.resume();
+        add_to_now(&mut setter, TimeDelta::minutes(5));
+
+        let expected = vec![
+            TimeSegment::new(
+                test_utils::new_utc_moment("2000-01-10 10:00:00"),
+                test_utils::new_utc_moment("2000-01-10 10:10:00"),
+            ),
+            TimeSegment::new_open(test_utils::new_utc_moment("2000-01-10 10:30:00")),
+        ];
+        pretty_assertions::assert_eq!(expected, watcher.running_segments());
+        assert_passed(&watcher, "00:15:00");
+        pretty_assertions::assert_eq!("00:20:00", watcher.paused_time().to_string());
+    }
+
+    #[test]
+    fn rebuild_segments_from_old_state_file() {
+        const OLD_PAUSED: &str = r#"{
+            "created_at": "2000-01-10T10:00:00Z",
+            "start_moment": "2000-01-10T10:00:00Z",
+            "last_paused_at": "2000-01-10T12:00:00Z",
+            "last_resume_moment": "2000-01-10T11:30:00Z",
+            "is_paused": true,
+            "paused_time": { "secs": 1800, "nanos": 0 },
+            "passed_time_between_pauses": { "secs": 5400, "nanos": 0 }
+        }"#;
+        let loaded_at = MockTimeImpl::new(test_utils::new_utc_moment("2000-01-10 13:00:00"));
+        let mut watcher: Stopwatch<MockTimeImpl> = serde_json::from_str(OLD_PAUSED).unwrap();
+        watcher.time_impl = loaded_at.clone();
+        let expected = vec![
+            TimeSegment::new(
+                test_utils::new_utc_moment("2000-01-10 10:00:00"),
+                test_utils::new_utc_moment("2000-01-10 11:00:00"),
+            ),
+            TimeSegment::new(
+                test_utils::new_utc_moment("2000-01-10 11:30:00"),
+                test_utils::new_utc_moment("2000-01-10 12:00:00"),
+            ),
+        ];
+        pretty_assertions::assert_eq!(expected, watcher.running_segments());
+        assert_passed(&watcher, "01:30:00");
+
+        const OLD_RUNNING: &str = r#"{
+            "created_at": "2000-01-10T10:00:00Z",
+            "start_moment": "2000-01-10T10:00:00Z",
+            "last_paused_at": "2000-01-10T10:30:00Z",
+            "last_resume_moment": "2000-01-10T11:00:00Z",
+            "is_paused": false,
+            "paused_time": { "secs": 1800, "nanos": 0 },
+            "passed_time_between_pauses": { "secs": 1800, "nanos": 0 }
+        }"#;
+        let mut watcher: Stopwatch<MockTimeImpl> = serde_json::from_str(OLD_RUNNING).unwrap();
+        watcher.time_impl = loaded_at;
+        let expected = vec![
+            TimeSegment::new(
+                test_utils::new_utc_moment("2000-01-10 10:00:00"),
+                test_utils::new_utc_moment("2000-01-10 10:30:00"),
+            ),
+            TimeSegment::new_open(test_utils::new_utc_moment("2000-01-10 11:00:00")),
+        ];
+        pretty_assertions::assert_eq!(expected, watcher.running_segments());
+    }
+
+    #[test]
+    fn adjust_passed_time() {
+        let (mut watcher, mut setter) = test_utils::new_mocked_stopwatch("2000-01-10 10:00:00");
+        add_to_now(&mut setter, TimeDelta::minutes(30));
+
+        let fifteen_minutes = ClockDuration::new_secs_mins_hours(None, Some(15), None);
+        watcher.adjust(
+            fifteen_minutes,
+            Some("Started before the command".to_string()),
+        );
+        assert_passed(&watcher, "00:45:00");
+        watcher.adjust(-fifteen_minutes - fifteen_minutes, None);
+        assert_passed(&watcher, "00:15:00");
+        // Adjustments do not count as pauses
+        pretty_assertions::assert_eq!("00:00:00", watcher.paused_time().to_string());
+
+        let adjustments = watcher.adjustments();
+        pretty_assertions::assert_eq!(2, adjustments.len());
+        pretty_assertions::assert_eq!(setter.now(), adjustments[0].recorded_at());
+        pretty_assertions::assert_eq!(Some("Started before the command"), adjustments[0].reason());
+        pretty_assertions::assert_eq!(-fifteen_minutes - fifteen_minutes, adjustments[1].amount());
+
+        watcher.adjust(-fifteen_minutes - fifteen_minutes, None);
+        // Passed time never becomes negative
+        assert_passed(&watcher, "00:00:00");
+    }
+
+    #[test]
+    fn moment_at_passed_time() {
+        let (mut watcher, mut setter) = test_utils::new_mocked_stopwatch("2000-01-10 10:00:00");
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        watcher.pause();
+        add_to_now(&mut setter, TimeDelta::minutes(20));
+        watcher.resume();
+        add_to_now(&mut setter, TimeDelta::minutes(5));
+
+        let minutes = |minutes| ClockDuration::new_secs_mins_hours(None, Some(minutes), None);
+        pretty_assertions::assert_eq!(
+            Some(test_utils::new_utc_moment("2000-01-10 10:05:00")),
+            watcher.moment_at_passed(minutes(5))
+        );
+        pretty_assertions::assert_eq!(
+            Some(test_utils::new_utc_moment("2000-01-10 10:32:00")),
+            watcher.moment_at_passed(minutes(12))
+        );
+        pretty_assertions::assert_eq!(None, watcher.moment_at_passed(minutes(16)));
+    }
+
+    #[test]
+    fn start_scheduled_in_future() {
+        let time_impl = MockTimeImpl::new(test_utils::new_utc_moment("2000-01-10 12:48:00"));
+        let mut setter = time_impl.clone();
+        let start = test_utils::new_utc_moment("2000-01-10 14:00:00");
+        let watcher = Stopwatch::new_with_impl_and_scheduled_start(time_impl, start);
+
+        assert!(watcher.is_scheduled());
+        pretty_assertions::assert_eq!(
+            Some("01:12:00".to_string()),
+            watcher.starts_in().map(|e| e.to_string())
+        );
+        assert_passed(&watcher, "00:00:00");
+        pretty_assertions::assert_eq!("00:00:00", watcher.paused_time().to_string());
+        assert!(watcher.running_segments().is_empty());
+
+        add_to_now(&mut setter, TimeDelta::hours(2));
+        assert!(!watcher.is_scheduled());
+        assert_passed(&watcher, "00:48:00");
+        pretty_assertions::assert_eq!(
+            vec![TimeSegment::new_open(start)],
+            watcher.running_segments()
+        );
+    }
+
+    #[test]
+    fn pause_cancels_scheduled_start() {
+        let time_impl = MockTimeImpl::new(test_utils::new_utc_moment("2000-01-10 12:00:00"));
+        let mut setter = time_impl.clone();
+        let start = test_utils::new_utc_moment("2000-01-10 14:00:00");
+        let mut watcher = Stopwatch::new_with_impl_and_scheduled_start(time_impl, start);
+
+        watcher.pause();
+        add_to_now(&mut setter, TimeDelta::hours(3));
+        assert!(watcher.is_paused());
+        assert_passed(&watcher, "00:00:00");
+        watcher.resume();
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        assert_passed(&watcher, "00:10:00");
+    }
+
+    #[test]
+    fn track_goal() {
+        let (mut watcher, mut setter) = test_utils::new_mocked_stopwatch("2000-01-10 10:00:00");
+        pretty_assertions::assert_eq!(None, watcher.goal_percent());
+        watcher.set_goal(Some(ClockDuration::new_secs_mins_hours(
+            Some(3),
+            None,
+            None,
+        )));
+
+        add_to_now(&mut setter, TimeDelta::minutes(90));
+        pretty_assertions::assert_eq!(Some(50), watcher.goal_percent());
+        pretty_assertions::assert_eq!(
+            Some("01:30:00".to_string()),
+            watcher.left_to_goal().map(|e| e.to_string())
+        );
+        pretty_assertions::assert_eq!(
+            Some("00:00:00".to_string()),
+            watcher.over_goal().map(|e| e.to_string())
+        );
+
+        add_to_now(&mut setter, TimeDelta::hours(2));
+        pretty_assertions::assert_eq!(Some(116), watcher.goal_percent());
+        pretty_assertions::assert_eq!(
+            Some("00:00:00".to_string()),
+            watcher.left_to_goal().map(|e| e.to_string())
+        );
+        pretty_assertions::assert_eq!(
+            Some("00:30:00".to_string()),
+            watcher.over_goal().map(|e| e.to_string())
+        );
+    }
+
+    #[test]
+    fn auto_stop_at_cap() {
+        let (mut watcher, mut setter) = test_utils::new_mocked_stopwatch("2000-01-10 10:00:00");
+        watcher.set_cap(Some(ClockDuration::new_secs_mins_hours(
+            Some(1),
+            None,
+            None,
+        )));
+        add_to_now(&mut setter, TimeDelta::minutes(30));
+        watcher.pause();
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        watcher.resume();
+        add_to_now(&mut setter, TimeDelta::minutes(80));
+
+        let stopped_at = test_utils::new_utc_moment("2000-01-10 11:10:00");
+        pretty_assertions::assert_eq!("01:00:00", watcher.passed().to_string());
+        pretty_assertions::assert_eq!("01:00:00", watcher.paused_time().to_string());
+        pretty_assertions::assert_eq!(ClockState::Paused, watcher.state());
+        pretty_assertions::assert_eq!(Some(stopped_at), watcher.last_paused_at());
+        pretty_assertions::assert_eq!(Some(stopped_at), watcher.auto_stopped_at());
+
+        watcher.apply_cap();
+        pretty_assertions::assert_eq!(
+            vec![
+                TimeSegment::new(
+                    test_utils::new_utc_moment("2000-01-10 10:00:00"),
+                    test_utils::new_utc_moment("2000-01-10 10:30:00")
+                ),
+                TimeSegment::new(
+                    test_utils::new_utc_moment("2000-01-10 10:40:00"),
+                    stopped_at
+                ),
+            ],
+            watcher.running_segments()
+        );
+        watcher.resume();
+        assert!(watcher.is_paused());
+
+        watcher.set_cap(None);
+        watcher.resume();
+        add_to_now(&mut setter, TimeDelta::minutes(5));
+        pretty_assertions::assert_eq!("01:05:00", watcher.passed().to_string());
+        pretty_assertions::assert_eq!(None, watcher.auto_stopped_at());
+    }
+
+    #[test]
+    fn guard_against_time_jumps() {
+        let (mut watcher, mut setter) = test_utils::new_mocked_stopwatch("2000-01-10 10:00:00");
+        add_to_now(&mut setter, TimeDelta::hours(1));
+        watcher.lap();
+        setter.set_now(test_utils::new_utc_moment("2000-01-10 10:30:00"));
+
+        let backwards_by = ClockDuration::new_secs_mins_hours(None, Some(30), None);
+        pretty_assertions::assert_eq!(
+            Some(backwards_by),
+            watcher.time_jump().map(|jump| jump.backwards_by())
+        );
+        pretty_assertions::assert_eq!("01:00:00", watcher.passed().to_string());
+        watcher.set_jump_policy(TimeJumpPolicy::Warn);
+        pretty_assertions::assert_eq!("00:30:00", watcher.passed().to_string());
+
+        watcher.set_jump_policy(TimeJumpPolicy::Record);
+        watcher.record_evaluation();
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        pretty_assertions::assert_eq!(None, watcher.time_jump());
+        pretty_assertions::assert_eq!(1, watcher.time_jumps().len());
+        pretty_assertions::assert_eq!("01:10:00", watcher.passed().to_string());
+    }
+
+    #[test]
+    fn change_speed_mid_run() {
+        let (mut watcher, mut setter) = test_utils::new_mocked_stopwatch("2000-01-10 10:00:00");
+        add_to_now(&mut setter, TimeDelta::minutes(10));
+        watcher.set_speed("10".parse().unwrap());
+        add_to_now(&mut setter, TimeDelta::minutes(3));
+        pretty_assertions::assert_eq!("00:40:00", watcher.passed().to_string());
+
+        watcher.pause();
+        add_to_now(&mut setter, TimeDelta::minutes(5));
+        watcher.set_speed("1/3".parse().unwrap());
+        watcher.resume();
+        add_to_now(&mut setter, TimeDelta::minutes(1));
+        pretty_assertions::assert_eq!("00:40:20", watcher.passed().to_string());
+        pretty_assertions::assert_eq!("00:05:00", watcher.paused_time().to_string());
+        pretty_assertions::assert_eq!(
+            Some(test_utils::new_utc_moment("2000-01-10 10:11:00")),
+            watcher.moment_at_passed(ClockDuration::new_secs_mins_hours(None, Some(20), None))
+        );
+        pretty_assertions::assert_eq!(2, watcher.rate_changes().len());
+
+        watcher.reset();
+        add_to_now(&mut setter, TimeDelta::minutes(3));
+        pretty_assertions::assert_eq!("00:01:00", watcher.passed().to_string());
+    }
 }
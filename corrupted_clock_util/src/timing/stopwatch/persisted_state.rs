@@ -0,0 +1,105 @@
+use serde::Deserialize;
+
+use crate::timing::{
+    Adjustment, ChronoDuration, ClockDuration, Lap, RateChange, Run, TimeJump, TimeSegment, Tz,
+    UtcDateTime,
+};
+
+use super::Stopwatch;
+
+/// Stopwatch as found in a state file.
+///
+/// State files written before the running segments were persisted only contain the summed up
+/// times. The segment history is then rebuilt from these fields.
+#[derive(Deserialize)]
+pub struct PersistedStopwatch {
+    created_at: UtcDateTime,
+    start_moment: UtcDateTime,
+    segments: Option<Vec<TimeSegment>>,
+    #[serde(default)]
+    laps: Vec<Lap>,
+    #[serde(default)]
+    adjustments: Vec<Adjustment>,
+    #[serde(default)]
+    goal: Option<ClockDuration>,
+    #[serde(default)]
+    group: Option<String>,
+    #[serde(default)]
+    time_zone: Option<Tz>,
+    #[serde(default)]
+    rate_changes: Vec<RateChange>,
+    #[serde(default)]
+    cap: Option<ClockDuration>,
+    #[serde(default)]
+    auto_stopped_at: Option<UtcDateTime>,
+    #[serde(default)]
+    last_seen_at: Option<UtcDateTime>,
+    #[serde(default)]
+    time_jumps: Vec<TimeJump>,
+    #[serde(default)]
+    runs: Vec<Run>,
+    #[serde(default)]
+    last_paused_at: Option<UtcDateTime>,
+    #[serde(default)]
+    last_resume_moment: Option<UtcDateTime>,
+    #[serde(default)]
+    is_paused: bool,
+    #[serde(default)]
+    passed_time_between_pauses: ClockDuration,
+}
+
+impl PersistedStopwatch {
+    fn rebuild_segments(&self) -> Vec<TimeSegment> {
+        let last_start = self.last_resume_moment.unwrap_or(self.start_moment);
+        let (last_segment, passed_in_last) = if self.is_paused {
+            let end = self.last_paused_at.unwrap_or(last_start);
+            (TimeSegment::new(last_start, end), end - last_start)
+        } else {
+            (TimeSegment::new_open(last_start), ChronoDuration::zero())
+        };
+        let passed_before_last =
+            ChronoDuration::from(self.passed_time_between_pauses) - passed_in_last;
+
+        let mut segments = Vec::with_capacity(2);
+        if passed_before_last > ChronoDuration::zero() {
+            // Only the sum of the earlier segments is known.
+            // They are merged into one segment right after the start.
+            segments.push(TimeSegment::new(
+                self.start_moment,
+                self.start_moment + passed_before_last,
+            ));
+        }
+        segments.push(last_segment);
+        segments
+    }
+}
+
+impl<T> From<PersistedStopwatch> for Stopwatch<T>
+where
+    T: Default,
+{
+    fn from(mut value: PersistedStopwatch) -> Self {
+        let segments = match value.segments.take() {
+            Some(segments) => segments,
+            None => value.rebuild_segments(),
+        };
+        Self {
+            created_at: value.created_at,
+            start_moment: value.start_moment,
+            segments,
+            laps: value.laps,
+            adjustments: value.adjustments,
+            goal: value.goal,
+            group: value.group,
+            time_zone: value.time_zone,
+            rate_changes: value.rate_changes,
+            cap: value.cap,
+            auto_stopped_at: value.auto_stopped_at,
+            last_seen_at: value.last_seen_at,
+            time_jumps: value.time_jumps,
+            runs: value.runs,
+            jump_policy: Default::default(),
+            time_impl: Default::default(),
+        }
+    }
+}
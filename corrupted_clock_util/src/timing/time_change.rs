@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ClockDuration, UtcDateTime};
+
+/// Change of the time a count down counts down from, for example to snooze it
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct TimeChange {
+    recorded_at: UtcDateTime,
+    previous: ClockDuration,
+    new: ClockDuration,
+}
+
+impl TimeChange {
+    pub fn new(recorded_at: UtcDateTime, previous: ClockDuration, new: ClockDuration) -> Self {
+        Self {
+            recorded_at,
+            previous,
+            new,
+        }
+    }
+
+    pub fn recorded_at(&self) -> UtcDateTime {
+        self.recorded_at
+    }
+
+    pub fn previous(&self) -> ClockDuration {
+        self.previous
+    }
+
+    pub fn new_time(&self) -> ClockDuration {
+        self.new
+    }
+
+    /// Negative if the count down was shortened
+    pub fn difference(&self) -> ClockDuration {
+        self.new - self.previous
+    }
+}
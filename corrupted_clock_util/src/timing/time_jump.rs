@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ClockDuration, UtcDateTime};
+
+/// Decides how a clock is evaluated once the system time lies before the last moment
+/// the clock has recorded, for example after a correction via NTP.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TimeJumpPolicy {
+    /// The clock is evaluated at the last recorded moment until the system time catches up.
+    #[default]
+    Clamp,
+    /// The clock is evaluated with the system time as it is.
+    Warn,
+    /// The jump is recorded as a correction. The clock continues from the last recorded moment.
+    Record,
+}
+
+impl std::fmt::Display for TimeJumpPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeJumpPolicy::Clamp => f.write_str("clamp"),
+            TimeJumpPolicy::Warn => f.write_str("warn"),
+            TimeJumpPolicy::Record => f.write_str("record"),
+        }
+    }
+}
+
+/// System time which went backwards behind the last recorded moment of a clock
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct TimeJump {
+    detected_at: UtcDateTime,
+    backwards_by: ClockDuration,
+}
+
+impl TimeJump {
+    pub fn new(detected_at: UtcDateTime, backwards_by: ClockDuration) -> Self {
+        Self {
+            detected_at,
+            backwards_by,
+        }
+    }
+
+    /// System time at which the jump was noticed
+    pub fn detected_at(&self) -> UtcDateTime {
+        self.detected_at
+    }
+
+    /// How far the system time lies before the last recorded moment
+    pub fn backwards_by(&self) -> ClockDuration {
+        self.backwards_by
+    }
+
+    pub(crate) fn sum_corrections<'a>(
+        jumps: impl IntoIterator<Item = &'a TimeJump>,
+    ) -> ClockDuration {
+        jumps
+            .into_iter()
+            .fold(ClockDuration::default(), |sum, next| {
+                sum + next.backwards_by
+            })
+    }
+}
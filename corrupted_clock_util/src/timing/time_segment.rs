@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ChronoDuration, ClockDuration, UtcDateTime};
+
+/// Interval in which a clock was running.
+/// A segment without an end is the one the clock is still running in.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct TimeSegment {
+    start: UtcDateTime,
+    end: Option<UtcDateTime>,
+}
+
+impl TimeSegment {
+    pub fn new(start: UtcDateTime, end: UtcDateTime) -> Self {
+        Self {
+            start,
+            end: Some(end),
+        }
+    }
+
+    pub fn new_open(start: UtcDateTime) -> Self {
+        Self { start, end: None }
+    }
+
+    pub fn start(&self) -> UtcDateTime {
+        self.start
+    }
+
+    pub fn end(&self) -> Option<UtcDateTime> {
+        self.end
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.end.is_none()
+    }
+
+    /// End of the segment or the given `now` if the segment is still open
+    pub fn end_or(&self, now: UtcDateTime) -> UtcDateTime {
+        self.end.unwrap_or(now)
+    }
+
+    pub fn duration(&self, now: UtcDateTime) -> ClockDuration {
+        (self.end_or(now) - self.start).into()
+    }
+
+    pub(crate) fn close(&mut self, end: UtcDateTime) {
+        self.end = Some(end);
+    }
+
+    pub(crate) fn sum_durations<'a>(
+        segments: impl IntoIterator<Item = &'a TimeSegment>,
+        now: UtcDateTime,
+    ) -> ClockDuration {
+        segments
+            .into_iter()
+            .fold(ChronoDuration::zero(), |sum, next| {
+                sum + (next.end_or(now) - next.start)
+            })
+            .into()
+    }
+}